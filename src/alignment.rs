@@ -0,0 +1,104 @@
+use egui::ahash::HashMap;
+use egui::{Align, Direction, Label, Layout, Response, Ui, WidgetText};
+use std::hash::Hash;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableTable};
+
+/// The layout that places the content of a cell with the given horizontal alignment
+pub fn aligned_layout(align: Align) -> Layout {
+    match align {
+        Align::Min => Layout::left_to_right(Align::Center),
+        Align::Center => Layout::centered_and_justified(Direction::LeftToRight),
+        Align::Max => Layout::right_to_left(Align::Center),
+    }
+}
+
+/// Functions related to the horizontal alignment of the columns
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Sets the horizontal alignment of the columns.
+    ///
+    /// The table does not change how the cells are rendered by `create_table_row` and
+    /// `create_header`. Query the alignment with [`column_alignment`](#method.column_alignment) or
+    /// use [`aligned_label`](#method.aligned_label) to keep the header and cells consistent.
+    /// Columns not in the map are aligned to the left.
+    ///
+    /// # Parameters:
+    /// - `alignment`: A map of columns to their horizontal alignment.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let mut alignment = HashMap::default();
+    /// alignment.insert(ColumnName::Amount, Align::Max);
+    /// table.set_column_alignment(alignment);
+    /// ```
+    pub fn set_column_alignment(&mut self, alignment: HashMap<F, Align>) {
+        self.column_alignment = alignment;
+    }
+
+    /// Returns the horizontal alignment of the column.
+    ///
+    /// # Parameters:
+    /// - `column`: The column to get the alignment of.
+    ///
+    /// # Returns:
+    /// - `Align`: The alignment set with [`set_column_alignment`](#method.set_column_alignment),
+    ///   or `Align::Min` if none was set.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let align = table.column_alignment(&ColumnName::Amount);
+    /// ```
+    pub fn column_alignment(&self, column: &F) -> Align {
+        self.column_alignment
+            .get(column)
+            .copied()
+            .unwrap_or(Align::Min)
+    }
+
+    /// Adds a label filling the cell with the text placed based on the alignment of the column.
+    ///
+    /// # Parameters:
+    /// - `ui`: The UI of the cell.
+    /// - `column`: The column the cell belongs to.
+    /// - `text`: The text of the label.
+    ///
+    /// # Returns:
+    /// - `Response`: The response of the label.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// // Inside create_table_row
+    /// table.aligned_label(ui, self, row.row_data.amount.to_string())
+    /// ```
+    pub fn aligned_label(&self, ui: &mut Ui, column: &F, text: impl Into<WidgetText>) -> Response {
+        let layout = aligned_layout(self.column_alignment(column));
+        ui.with_layout(layout, |ui| ui.add(Label::new(text).selectable(false)))
+            .inner
+    }
+
+    /// Sets the horizontal alignment of the row numbers in the serial column. Default: `Align::Center`
+    ///
+    /// # Parameters:
+    /// - `align`: The horizontal alignment of the serial column.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_serial_column_alignment(Align::Max);
+    /// ```
+    pub const fn set_serial_column_alignment(&mut self, align: Align) {
+        self.serial_column_alignment = align;
+    }
+}
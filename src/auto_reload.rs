@@ -6,11 +6,12 @@ use crate::{ColumnOperations, ColumnOrdering, SelectableTable};
 pub struct AutoReload {
     pub reload_after: Option<u32>,
     pub reload_count: u32,
+    pub keep_selection: bool,
 }
 impl AutoReload {
     /// Increase the current reload count and return bool based on if it is equal or above the count it is
     /// supposed to reload at
-    pub(crate) fn increment_count(&mut self) -> bool {
+    pub(crate) const fn increment_count(&mut self) -> bool {
         self.reload_count += 1;
         if let Some(count) = self.reload_after {
             let reload = self.reload_count >= count;
@@ -77,8 +78,38 @@ where
     /// table.set_auto_reload(Some(1000)); // Reload after 1000 updates.
     /// table.set_auto_reload(None); // Disable auto-reloading.
     /// ```
-    pub fn set_auto_reload(&mut self, count: Option<u32>) {
+    pub const fn set_auto_reload(&mut self, count: Option<u32>) {
         self.auto_reload.reload_after = count;
         self.auto_reload.reload_count = 0;
     }
+
+    /// Keep the current selection when rows are automatically recreated.
+    ///
+    /// By default, an automatic reload calls [`recreate_rows`](#method.recreate_rows) which clears
+    /// the selection. After calling this method, automatic reloads use
+    /// [`recreate_rows_no_unselect`](#method.recreate_rows_no_unselect) instead.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .auto_reload(500).auto_reload_keep_selection();
+    /// ```
+    #[must_use]
+    pub const fn auto_reload_keep_selection(mut self) -> Self {
+        self.auto_reload.keep_selection = true;
+        self
+    }
+
+    /// Sets whether the current selection should be kept when rows are automatically recreated.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to keep the selection on automatic reload, `false` to clear it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_auto_reload_keep_selection(true);
+    /// ```
+    pub const fn set_auto_reload_keep_selection(&mut self, status: bool) {
+        self.auto_reload.keep_selection = status;
+    }
 }
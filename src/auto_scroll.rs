@@ -1,5 +1,6 @@
-use egui::{Pos2, Rect};
+use egui::{Pos2, Rect, Ui, Vec2};
 use std::hash::Hash;
+use std::time::Duration;
 
 use crate::{ColumnOperations, ColumnOrdering, SelectableTable};
 
@@ -108,6 +109,16 @@ impl AutoScroll {
         self
     }
 
+    /// Whether the pointer is close enough to the top or bottom edge to trigger auto-scrolling
+    pub(crate) fn is_pointer_at_edge(&self, max_rect: Rect, pointer: Option<Pos2>) -> bool {
+        let Some(loc) = pointer else {
+            return false;
+        };
+        let min_y = max_rect.min.y + self.distance_from_min;
+        let max_y = max_rect.max.y - self.distance_from_max;
+        loc.y < min_y || loc.y > max_y
+    }
+
     /// Calculate the position based on the rectangle and return the new vertical offset
     pub(crate) fn start_scroll(&mut self, max_rect: Rect, pointer: Option<Pos2>) -> Option<f32> {
         if !self.enabled {
@@ -180,10 +191,72 @@ where
         + ColumnOrdering<Row>,
     Conf: Default,
 {
-    pub(crate) fn update_scroll_offset(&mut self, offset: f32) {
+    pub(crate) const fn update_scroll_offset(&mut self, offset: f32) {
         self.auto_scroll.scroll_offset = offset;
     }
 
+    /// Save the area of the vertical scrollbar of the table body so drags on it can be told
+    /// apart from drags on the cells
+    pub(crate) fn update_scrollbar_rect(&mut self, ui: &Ui, inner_rect: Rect, content_size: Vec2) {
+        if content_size.y <= inner_rect.height() {
+            self.scrollbar_rect = None;
+            return;
+        }
+
+        // Floating scrollbars are drawn over the content, solid ones are placed next to it
+        let scroll = ui.spacing().scroll;
+        let max_x = inner_rect.max.x + scroll.allocated_width();
+        let min_x = max_x - scroll.bar_outer_margin - scroll.bar_width;
+        self.scrollbar_rect = Some(inner_rect.with_min_x(min_x).with_max_x(max_x));
+    }
+
+    /// Returns whether the pointer is over the vertical scrollbar of the table body.
+    ///
+    /// Drag selection and auto-scrolling are paused while the pointer is over the scrollbar so
+    /// grabbing it during a drag does not select cells or fight the scroll.
+    ///
+    /// # Returns:
+    /// - `bool`: `true` if the pointer is over the scrollbar in the current frame.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// if !table.pointer_over_scrollbar() {
+    ///     // Show a tooltip for the hovered row
+    /// }
+    /// ```
+    pub const fn pointer_over_scrollbar(&self) -> bool {
+        self.pointer_over_scrollbar
+    }
+
+    /// Returns the new vertical offset during a drag, using the custom auto-scroll function if
+    /// one is set
+    pub(crate) fn drag_scroll_offset(
+        &mut self,
+        max_rect: Rect,
+        pointer: Option<Pos2>,
+        time: f64,
+    ) -> Option<f32> {
+        let Some(scroll_fn) = self.auto_scroll_fn.as_ref() else {
+            return self.auto_scroll.start_scroll(max_rect, pointer);
+        };
+
+        if !self.auto_scroll.enabled {
+            return None;
+        }
+
+        let time_at_edge = if self.auto_scroll.is_pointer_at_edge(max_rect, pointer) {
+            let since = *self.edge_hover_since.get_or_insert(time);
+            Duration::from_secs_f64(time - since)
+        } else {
+            self.edge_hover_since = None;
+            Duration::ZERO
+        };
+
+        let delta = scroll_fn(max_rect, pointer, time_at_edge)?;
+        self.auto_scroll.scroll_offset = (self.auto_scroll.scroll_offset + delta).max(0.0);
+        Some(self.auto_scroll.scroll_offset)
+    }
+
     /// Enables auto-scrolling when dragging near the edges of the view.
     ///
     /// # Returns:
@@ -248,7 +321,37 @@ where
     /// let new_scroll_settings = AutoScroll::new(true).max_speed(60.0);
     /// table.update_auto_scroll(new_scroll_settings); // Update the auto-scroll settings during runtime
     /// ```
-    pub fn update_auto_scroll(&mut self, scroll: AutoScroll) {
+    pub const fn update_auto_scroll(&mut self, scroll: AutoScroll) {
         self.auto_scroll = scroll;
     }
+
+    /// Sets a custom function for calculating the auto-scroll amount during a drag, replacing the
+    /// default behavior of [`AutoScroll`].
+    ///
+    /// The function is called each frame while dragging with auto-scrolling enabled. It receives
+    /// the rect of the table, the pointer position and how long the pointer has been near the
+    /// top or bottom edge, as defined by the `AutoScroll` distances. It returns the amount to
+    /// scroll by in this frame, negative for upwards, or `None` to not scroll.
+    ///
+    /// # Parameters:
+    /// - `scroll_fn`: The function calculating the scroll amount.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// // Scroll faster the longer the pointer stays at the edge
+    /// table.set_auto_scroll_fn(|rect, pointer, time_at_edge| {
+    ///     let pointer = pointer?;
+    ///     if time_at_edge.is_zero() {
+    ///         return None;
+    ///     }
+    ///     let speed = 10.0 + time_at_edge.as_secs_f32() * 20.0;
+    ///     Some(if pointer.y < rect.center().y { -speed } else { speed })
+    /// });
+    /// ```
+    pub fn set_auto_scroll_fn<Fn>(&mut self, scroll_fn: Fn)
+    where
+        Fn: std::ops::Fn(Rect, Option<Pos2>, Duration) -> Option<f32> + Send + 'static,
+    {
+        self.auto_scroll_fn = Some(Box::new(scroll_fn));
+    }
 }
@@ -0,0 +1,113 @@
+use std::hash::Hash;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableTable};
+
+/// Restores the batching state once a batch ends, including when the closure panics
+struct BatchGuard<'a, Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    table: &'a mut SelectableTable<Row, F, Conf>,
+    was_batching: bool,
+}
+
+impl<Row, F, Conf> Drop for BatchGuard<'_, Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    fn drop(&mut self) {
+        self.table.batching = self.was_batching;
+        if !self.was_batching {
+            self.table.batched_row_ids.clear();
+        }
+    }
+}
+
+/// Functions related to applying many changes at once
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Runs the closure as a single batch of changes.
+    ///
+    /// While the closure runs, [`auto_reload`](#method.auto_reload) does not recreate the rows
+    /// and the callback set with [`set_on_row_added`](#method.set_on_row_added) is not called.
+    /// Once it returns, the rows are recreated once without clearing the selection and the row
+    /// added callback is called for each row added during the batch. Nested batches are applied
+    /// when the outermost one ends.
+    ///
+    /// If the closure panics, the table leaves the batch without recreating the rows or calling
+    /// the row added callback for the rows added so far.
+    ///
+    /// # Parameters:
+    /// - `changes`: A closure that makes the changes to the table.
+    ///
+    /// # Returns:
+    /// - `R`: The value returned by the closure.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.batch(|table| {
+    ///     for row in new_rows {
+    ///         table.add_modify_row(|_| Some(row));
+    ///     }
+    /// });
+    /// ```
+    pub fn batch<R, Fn>(&mut self, changes: Fn) -> R
+    where
+        Fn: FnOnce(&mut Self) -> R,
+    {
+        let was_batching = std::mem::replace(&mut self.batching, true);
+        let guard = BatchGuard {
+            table: self,
+            was_batching,
+        };
+        let result = changes(&mut *guard.table);
+        if was_batching {
+            return result;
+        }
+
+        let added_ids = std::mem::take(&mut guard.table.batched_row_ids);
+        drop(guard);
+
+        self.auto_reload.reload_count = 0;
+        self.recreate_rows_no_unselect();
+
+        if let Some(callback) = self.on_row_added.as_mut() {
+            for id in added_ids {
+                callback(id);
+            }
+        }
+        result
+    }
+}
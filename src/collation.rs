@@ -0,0 +1,71 @@
+use std::cmp::Ordering;
+
+/// Appends the lowercase form of the character without its diacritics. Covers the Latin-1 and
+/// Latin Extended-A letters, other characters are only lowercased
+fn push_folded(folded: &mut String, c: char) {
+    let base = match c {
+        'À'..='Å' | 'à'..='å' | 'Ā'..='ą' => 'a',
+        'Ç' | 'ç' | 'Ć'..='č' => 'c',
+        'Ď'..='đ' | 'Ð' | 'ð' => 'd',
+        'È'..='Ë' | 'è'..='ë' | 'Ē'..='ě' => 'e',
+        'Ĝ'..='ģ' => 'g',
+        'Ĥ'..='ħ' => 'h',
+        'Ì'..='Ï' | 'ì'..='ï' | 'Ĩ'..='ı' => 'i',
+        'Ĵ' | 'ĵ' => 'j',
+        'Ķ' | 'ķ' => 'k',
+        'Ĺ'..='ł' => 'l',
+        'Ñ' | 'ñ' | 'Ń'..='ň' => 'n',
+        'Ò'..='Ö' | 'Ø' | 'ò'..='ö' | 'ø' | 'Ō'..='ő' => 'o',
+        'Ŕ'..='ř' => 'r',
+        'Ś'..='š' => 's',
+        'Ţ'..='ŧ' => 't',
+        'Ù'..='Ü' | 'ù'..='ü' | 'Ũ'..='ų' => 'u',
+        'Ŵ' | 'ŵ' => 'w',
+        'Ý' | 'ý' | 'ÿ' | 'Ŷ'..='Ÿ' => 'y',
+        'Ź'..='ž' => 'z',
+        'ß' => return folded.push_str("ss"),
+        'Æ' | 'æ' => return folded.push_str("ae"),
+        'Œ' | 'œ' => return folded.push_str("oe"),
+        _ => return folded.extend(c.to_lowercase()),
+    };
+    folded.push(base);
+}
+
+/// The text lowercased and without diacritics
+fn fold(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.chars() {
+        push_folded(&mut folded, c);
+    }
+    folded
+}
+
+/// Compares two strings ignoring case and diacritics, so `émile` is placed next to `Emile`
+/// instead of after `zoe` like a byte comparison does.
+///
+/// Covers the accented letters of the Latin alphabets, other scripts are only compared without
+/// case. Strings that are equal this way are ordered by their bytes, so the result is
+/// consistent. Each call allocates, so cache the folded text for very large tables.
+///
+/// # Parameters:
+/// - `a`: The first string.
+/// - `b`: The second string.
+///
+/// # Returns:
+/// - `Ordering`: The ordering of `a` relative to `b`.
+///
+/// # Example:
+/// ```rust,ignore
+/// impl ColumnOrdering<MyRow> for ColumnName {
+///     fn order_by(&self, row_1: &MyRow, row_2: &MyRow) -> Ordering {
+///         match self {
+///             ColumnName::Name => locale_cmp(&row_1.name, &row_2.name),
+///             // ...
+///         }
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn locale_cmp(a: &str, b: &str) -> Ordering {
+    fold(a).cmp(&fold(b)).then_with(|| a.cmp(b))
+}
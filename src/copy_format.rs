@@ -0,0 +1,277 @@
+use egui::ahash::{HashMap, HashMapExt};
+use std::fmt::Write;
+use std::hash::Hash;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableRow};
+
+/// Trait for defining how the selected cells are formatted when copied to the clipboard.
+///
+/// Each entry of `rows` contains a selected row in display order along with the columns that
+/// should be part of the copied output, in column order. A column in that list that is not
+/// selected in the row should be treated as an empty cell.
+///
+/// # Example
+/// ```rust,ignore
+/// struct PipeFormatter;
+///
+/// impl CopyFormatter<MyRow, ColumnName, Config> for PipeFormatter {
+///     fn format(&self, rows: &[(&SelectableRow<MyRow, ColumnName>, &[ColumnName])]) -> String {
+///         // build the text
+///     }
+/// }
+/// ```
+pub trait CopyFormatter<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Format the selected rows into the text that will be copied.
+    ///
+    /// # Arguments
+    /// * `rows` - The selected rows in display order with the columns to include for each.
+    ///
+    /// # Returns
+    /// * `String` - The text to copy to the clipboard.
+    fn format(&self, rows: &[(&SelectableRow<Row, F>, &[F])]) -> String;
+}
+
+/// Returns the text of the cell if it is selected in the row, otherwise an empty string
+fn cell_text<Row, F, Conf>(row: &SelectableRow<Row, F>, column: &F) -> String
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    if row.selected_columns.contains(column) {
+        column.column_text(&row.row_data)
+    } else {
+        String::new()
+    }
+}
+
+/// Unselects the cells that have the same text as the cell above them in the same column, so
+/// they are copied as empty cells like merged cells in a spreadsheet
+pub fn dedup_rows<Row, F, Conf>(
+    rows: &[&SelectableRow<Row, F>],
+    columns: &[F],
+) -> Vec<SelectableRow<Row, F>>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    let mut previous_text: HashMap<&F, String> = HashMap::new();
+
+    rows.iter()
+        .map(|row| {
+            let mut row = (*row).clone();
+            for column in columns {
+                if !row.selected_columns.contains(column) {
+                    // An empty cell ends the run of repeated values
+                    previous_text.remove(column);
+                    continue;
+                }
+                let text = column.column_text(&row.row_data);
+                if previous_text.get(column) == Some(&text) {
+                    row.selected_columns.remove(column);
+                } else {
+                    previous_text.insert(column, text);
+                }
+            }
+            row
+        })
+        .collect()
+}
+
+/// Formats the cells as plain text with each column padded to the same width. This is the
+/// default formatter and is meant to be readable when pasted into a text editor.
+pub struct AlignedFormatter;
+
+impl<Row, F, Conf> CopyFormatter<Row, F, Conf> for AlignedFormatter
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    fn format(&self, rows: &[(&SelectableRow<Row, F>, &[F])]) -> String {
+        let mut column_max_length = HashMap::new();
+
+        // Keep track of the biggest length of a value of a column
+        for (row, columns) in rows {
+            for column in *columns {
+                let field_length = cell_text(row, column).len();
+                let entry = column_max_length.entry(column).or_insert(0);
+                if field_length > *entry {
+                    *entry = field_length;
+                }
+            }
+        }
+
+        let mut to_copy = String::new();
+
+        // Target is to ensure a fixed length after each column value of a row
+        // If for example highest len is 10 but the current row's
+        // column value is 5, we will add the column value and add 5 more space after that
+        // to ensure alignment
+        for (row, columns) in rows {
+            for column in *columns {
+                let width = column_max_length[column] + 1;
+                let column_text = cell_text(row, column);
+                let _ = write!(to_copy, "{column_text:<width$}");
+            }
+            to_copy.push('\n');
+        }
+        to_copy
+    }
+}
+
+/// Formats the cells as tab separated values. Tabs and line breaks inside a value are replaced
+/// with spaces.
+pub struct TsvFormatter;
+
+impl<Row, F, Conf> CopyFormatter<Row, F, Conf> for TsvFormatter
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    fn format(&self, rows: &[(&SelectableRow<Row, F>, &[F])]) -> String {
+        let mut to_copy = String::new();
+
+        for (row, columns) in rows {
+            let row_text: Vec<String> = columns
+                .iter()
+                .map(|column| cell_text(row, column).replace(['\t', '\n', '\r'], " "))
+                .collect();
+            to_copy.push_str(&row_text.join("\t"));
+            to_copy.push('\n');
+        }
+        to_copy
+    }
+}
+
+/// Quotes the value for a CSV file if it contains a comma, a quote or a line break
+pub fn csv_field(text: String) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+}
+
+/// Formats the cells as comma separated values. Values containing a comma, a quote or a line
+/// break are quoted.
+pub struct CsvFormatter;
+
+impl<Row, F, Conf> CopyFormatter<Row, F, Conf> for CsvFormatter
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    fn format(&self, rows: &[(&SelectableRow<Row, F>, &[F])]) -> String {
+        let mut to_copy = String::new();
+
+        for (row, columns) in rows {
+            let row_text: Vec<String> = columns
+                .iter()
+                .map(|column| csv_field(cell_text(row, column)))
+                .collect();
+            to_copy.push_str(&row_text.join(","));
+            to_copy.push('\n');
+        }
+        to_copy
+    }
+}
+
+/// Formats the cells as a Markdown table. The header row is left empty as the headers are
+/// rendered by the user and have no text representation.
+pub struct MarkdownFormatter;
+
+impl<Row, F, Conf> CopyFormatter<Row, F, Conf> for MarkdownFormatter
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    fn format(&self, rows: &[(&SelectableRow<Row, F>, &[F])]) -> String {
+        let Some((_, first_columns)) = rows.first() else {
+            return String::new();
+        };
+
+        let mut to_copy = String::from("|");
+        to_copy.push_str(&" |".repeat(first_columns.len()));
+        to_copy.push_str("\n|");
+        to_copy.push_str(&" --- |".repeat(first_columns.len()));
+        to_copy.push('\n');
+
+        for (row, columns) in rows {
+            to_copy.push('|');
+            for column in *columns {
+                let text = cell_text(row, column)
+                    .replace('|', "\\|")
+                    .replace(['\n', '\r'], " ");
+                let _ = write!(to_copy, " {text} |");
+            }
+            to_copy.push('\n');
+        }
+        to_copy
+    }
+}
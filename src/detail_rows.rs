@@ -0,0 +1,179 @@
+use egui::{Pos2, Rect, Ui, UiBuilder};
+use egui_extras::TableRow;
+use std::hash::Hash;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableRow, SelectableTable};
+
+/// A row of the table body, either a displayed row or the detail row below it
+#[derive(Clone, Copy)]
+pub struct BodyRow {
+    /// The index of the displayed row in `formatted_rows`
+    pub index: usize,
+    /// Whether this is the detail row of the displayed row
+    pub is_detail: bool,
+    /// The height the row is drawn with
+    pub height: f32,
+}
+
+/// Functions related to showing a detail row below expanded rows
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// The rows of the body in display order, with a detail row after each expanded row
+    pub(crate) fn body_layout(&self) -> Vec<BodyRow> {
+        let mut layout = Vec::with_capacity(self.formatted_rows.len() + self.expanded_rows.len());
+        for (index, row) in self.formatted_rows.iter().enumerate() {
+            layout.push(BodyRow {
+                index,
+                is_detail: false,
+                height: self.row_height_of(row.id),
+            });
+            if self.expanded_rows.contains(&row.id) {
+                layout.push(BodyRow {
+                    index,
+                    is_detail: true,
+                    height: self.detail_height,
+                });
+            }
+        }
+        layout
+    }
+
+    /// The position in the body of the displayed row at the index, counting the detail rows
+    /// above it
+    pub(crate) fn body_index(&self, index: usize) -> usize {
+        let details_above = self
+            .expanded_rows
+            .iter()
+            .filter(|id| self.indexed_ids.get(id).is_some_and(|row| *row < index))
+            .count();
+        index + details_above
+    }
+
+    /// Builds the detail row of the displayed row at the index. The detail is drawn from the
+    /// first cell across the width of the whole table
+    pub(crate) fn build_detail(&mut self, mut row: TableRow, index: usize) {
+        let row_data = self.formatted_rows[index].clone();
+
+        for column_num in 0..self.expected_column_count() {
+            row.col(|ui| {
+                if column_num == 0 {
+                    self.show_detail(ui, &row_data);
+                }
+            });
+        }
+    }
+
+    fn show_detail(&mut self, ui: &mut Ui, row_data: &SelectableRow<Row, F>) {
+        let Some(callback) = self.on_detail.as_mut() else {
+            return;
+        };
+
+        let cell_rect = ui.max_rect();
+        let max_x = if self.body_rect.is_positive() {
+            self.body_rect.max.x
+        } else {
+            cell_rect.max.x
+        };
+        let detail_rect = Rect::from_min_max(cell_rect.min, Pos2::new(max_x, cell_rect.max.y));
+        let mut clip_rect = ui.clip_rect();
+        clip_rect.max.x = max_x;
+
+        let mut detail_ui = ui.new_child(UiBuilder::new().max_rect(detail_rect));
+        detail_ui.set_clip_rect(clip_rect);
+        callback(&mut detail_ui, row_data);
+    }
+
+    /// Expands or collapses a row. An expanded row shows a detail row below it, drawn by the
+    /// callback set with [`set_on_detail`](#method.set_on_detail).
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row.
+    /// - `expanded`: `true` to show the detail row, `false` to hide it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// // Inside create_table_row
+    /// if ui.button("▶").clicked() {
+    ///     expand_requests.push(row.id);
+    /// }
+    /// // After show_ui
+    /// for id in expand_requests {
+    ///     table.set_expanded(id, !table.is_expanded(id));
+    /// }
+    /// ```
+    pub fn set_expanded(&mut self, id: i64, expanded: bool) {
+        if expanded {
+            self.expanded_rows.insert(id);
+        } else {
+            self.expanded_rows.remove(&id);
+        }
+    }
+
+    /// Returns whether the row is expanded with [`set_expanded`](#method.set_expanded).
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let arrow = if table.is_expanded(row.id) { "▼" } else { "▶" };
+    /// ```
+    pub fn is_expanded(&self, id: i64) -> bool {
+        self.expanded_rows.contains(&id)
+    }
+
+    /// Collapses every expanded row.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.collapse_all();
+    /// ```
+    pub fn collapse_all(&mut self) {
+        self.expanded_rows.clear();
+    }
+
+    /// Sets the height of the detail rows shown below the expanded rows. Default: 100.0
+    ///
+    /// # Parameters:
+    /// - `height`: The detail row height in pixels.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_detail_height(150.0);
+    /// ```
+    pub const fn set_detail_height(&mut self, height: f32) {
+        self.detail_height = height;
+    }
+
+    /// Sets the callback that draws the detail row below each expanded row.
+    ///
+    /// The callback receives a UI spanning every column of the table and the expanded row.
+    ///
+    /// # Parameters:
+    /// - `callback`: A closure that receives the UI of the detail row and the expanded row.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_on_detail(|ui, row| {
+    ///     ui.label(format!("Notes: {}", row.row_data.notes));
+    /// });
+    /// ```
+    pub fn set_on_detail<Fn>(&mut self, callback: Fn)
+    where
+        Fn: FnMut(&mut Ui, &SelectableRow<Row, F>) + Send + 'static,
+    {
+        self.on_detail = Some(Box::new(callback));
+    }
+}
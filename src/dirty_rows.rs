@@ -0,0 +1,117 @@
+use egui::ahash::HashSet;
+use egui::Color32;
+use std::hash::Hash;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableTable};
+
+/// Functions related to tracking rows with unsaved changes
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Marks a row as modified, for example after it was edited and before it is saved.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the modified row.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.modify_shown_row(|rows, indexed_ids| { /* edit the row */ });
+    /// table.mark_dirty(row_id);
+    /// ```
+    pub fn mark_dirty(&mut self, id: i64) {
+        self.dirty_rows.insert(id);
+    }
+
+    /// Removes the modified mark of a row, for example after its changes were saved.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.clear_dirty(row_id);
+    /// ```
+    pub fn clear_dirty(&mut self, id: i64) {
+        self.dirty_rows.remove(&id);
+    }
+
+    /// Removes the modified mark of every row.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.commit_shown_to_rows();
+    /// table.clear_all_dirty();
+    /// ```
+    pub fn clear_all_dirty(&mut self) {
+        self.dirty_rows.clear();
+    }
+
+    /// Returns whether the row is marked as modified.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let modified = table.is_dirty(row_id);
+    /// ```
+    pub fn is_dirty(&self, id: i64) -> bool {
+        self.dirty_rows.contains(&id)
+    }
+
+    /// Returns the IDs of every row marked as modified.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// if !table.dirty_rows().is_empty() && ui.button("Save changes").clicked() {
+    ///     // save the rows
+    /// }
+    /// ```
+    pub const fn dirty_rows(&self) -> &HashSet<i64> {
+        &self.dirty_rows
+    }
+
+    /// Paints a background color behind the rows marked as modified with
+    /// [`mark_dirty`](#method.mark_dirty).
+    ///
+    /// # Parameters:
+    /// - `color`: The background color of the modified rows.
+    ///
+    /// # Returns:
+    /// - `Self`: The modified table with the modified rows tinted.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .dirty_row_tint(Color32::from_rgba_unmultiplied(255, 200, 0, 30));
+    /// ```
+    #[must_use]
+    pub const fn dirty_row_tint(mut self, color: Color32) -> Self {
+        self.dirty_row_tint = Some(color);
+        self
+    }
+
+    /// Sets a background color painted behind the rows marked as modified. Default: `None`
+    ///
+    /// # Parameters:
+    /// - `color`: The background color of the modified rows, or `None` to not paint them.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_dirty_row_tint(Some(Color32::from_rgba_unmultiplied(255, 200, 0, 30)));
+    /// ```
+    pub const fn set_dirty_row_tint(&mut self, color: Option<Color32>) {
+        self.dirty_row_tint = color;
+    }
+}
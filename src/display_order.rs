@@ -0,0 +1,144 @@
+use egui::ahash::{HashSet, HashSetExt};
+use std::hash::Hash;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableTable};
+
+/// Functions related to displaying the rows in a custom order
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Reorders the displayed rows to follow the given order of row IDs.
+    ///
+    /// Displayed rows not in `ids` keep their relative order and are placed after the ordered
+    /// ones. IDs that are not displayed are ignored. The selection is kept. The order is lost
+    /// the next time the rows are recreated, use
+    /// [`set_sticky_display_order`](#method.set_sticky_display_order) to keep it.
+    ///
+    /// # Parameters:
+    /// - `ids`: The row IDs in the order they should be displayed.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_display_order(vec![3, 1, 2]);
+    /// ```
+    pub fn set_display_order(&mut self, ids: Vec<i64>) {
+        let mut ordered = Vec::with_capacity(self.formatted_rows.len());
+        let mut taken = HashSet::new();
+
+        for id in ids {
+            if !taken.insert(id) {
+                continue;
+            }
+            if let Some(index) = self.indexed_ids.get(&id) {
+                ordered.push(self.formatted_rows[*index].clone());
+            }
+        }
+
+        ordered.extend(
+            self.formatted_rows
+                .drain(..)
+                .filter(|row| !taken.contains(&row.id)),
+        );
+
+        // Hidden rows are not displayed so they are placed after the displayed ones
+        let mut sorted_ids: Vec<i64> = ordered.iter().map(|row| row.id).collect();
+        sorted_ids.extend(
+            self.sorted_ids
+                .iter()
+                .filter(|id| self.hidden_rows.contains(id)),
+        );
+        self.sorted_ids = sorted_ids;
+
+        self.indexed_ids = ordered
+            .iter()
+            .enumerate()
+            .map(|(index, row)| (row.id, index))
+            .collect();
+        self.formatted_rows = ordered;
+    }
+
+    /// Reorders the displayed rows like [`set_display_order`](#method.set_display_order) and
+    /// keeps using the order whenever the rows are recreated, instead of sorting them.
+    ///
+    /// Rows that still exist keep their custom position and newly added rows are appended in the
+    /// order they were added. Useful for keeping a custom order while rows are being streamed in
+    /// with [`auto_reload`](#method.auto_reload). Pinned rows are still displayed at the top.
+    ///
+    /// # Parameters:
+    /// - `ids`: The row IDs in the order they should be displayed.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_sticky_display_order(vec![3, 1, 2]);
+    /// ```
+    pub fn set_sticky_display_order(&mut self, ids: Vec<i64>) {
+        self.set_display_order(ids.clone());
+        self.sticky_order = Some(ids);
+    }
+
+    /// Stops using the order set with
+    /// [`set_sticky_display_order`](#method.set_sticky_display_order). You must call
+    /// [`recreate_rows`](#method.recreate_rows) to display the rows in the sorted order again.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.clear_sticky_order();
+    /// table.recreate_rows();
+    /// ```
+    pub fn clear_sticky_order(&mut self) {
+        self.sticky_order = None;
+    }
+
+    /// Freezes the current order of the rows so recreating them does not move any row.
+    ///
+    /// While frozen, recreating the rows, including through
+    /// [`auto_reload`](#method.auto_reload), still updates the data of each row but keeps it at
+    /// its current position. New rows are appended at the bottom in the order they were added.
+    /// Useful for keeping the rows still while the user is reading during a burst of updates.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// if response.hovered() {
+    ///     table.freeze_sort();
+    /// } else if table.is_sort_frozen() {
+    ///     table.unfreeze_sort();
+    /// }
+    /// ```
+    pub fn freeze_sort(&mut self) {
+        self.frozen_order = Some(self.sorted_ids.clone());
+    }
+
+    /// Unfreezes the order frozen with [`freeze_sort`](#method.freeze_sort) and re-sorts the rows
+    /// while keeping the current selection.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.unfreeze_sort();
+    /// ```
+    pub fn unfreeze_sort(&mut self) {
+        if self.frozen_order.take().is_some() {
+            self.recreate_rows_no_unselect();
+        }
+    }
+
+    /// Returns whether the order of the rows is frozen with [`freeze_sort`](#method.freeze_sort).
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let frozen = table.is_sort_frozen();
+    /// ```
+    pub const fn is_sort_frozen(&self) -> bool {
+        self.frozen_order.is_some()
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableTable};
+
+/// Previous row data of the rows edited with `edit_row`
+pub struct EditHistory<Row> {
+    /// The row ID and its data before each edit, the latest edit last
+    pub undo: VecDeque<(i64, Row)>,
+    /// The row ID and its data before each undo, the latest undo last
+    pub redo: Vec<(i64, Row)>,
+    /// The maximum number of edits that can be undone
+    pub depth: usize,
+}
+
+impl<Row> Default for EditHistory<Row> {
+    fn default() -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            depth: 100,
+        }
+    }
+}
+
+/// Functions related to editing rows with undo and redo
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Edits the data of a row and saves the previous data so the edit can be undone with
+    /// [`undo_edit`](#method.undo_edit).
+    ///
+    /// Making an edit clears the edits that can be redone. The rows are recreated without
+    /// clearing the selection to display the change.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row to edit.
+    /// - `edit`: A closure that modifies the row data.
+    ///
+    /// # Returns:
+    /// - `bool`: `true` if the row exists and was edited.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.edit_row(row_id, |row| row.name = new_name);
+    /// ```
+    pub fn edit_row<Fn>(&mut self, id: i64, edit: Fn) -> bool
+    where
+        Fn: FnOnce(&mut Row),
+    {
+        let Some(row) = self.rows.get_mut(&id) else {
+            return false;
+        };
+
+        let history = &mut self.edit_history;
+        if history.depth > 0 {
+            if history.undo.len() == history.depth {
+                history.undo.pop_front();
+            }
+            history.undo.push_back((id, row.row_data.clone()));
+        }
+        history.redo.clear();
+
+        edit(&mut row.row_data);
+        if !self.batching {
+            self.recreate_rows_no_unselect();
+        }
+        true
+    }
+
+    /// Reverts the latest edit made with [`edit_row`](#method.edit_row).
+    ///
+    /// Edits of rows that were removed since are skipped.
+    ///
+    /// # Returns:
+    /// - `bool`: `true` if an edit was reverted.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.undo_edit();
+    /// ```
+    pub fn undo_edit(&mut self) -> bool {
+        while let Some((id, previous)) = self.edit_history.undo.pop_back() {
+            let Some(row) = self.rows.get_mut(&id) else {
+                continue;
+            };
+            let current = std::mem::replace(&mut row.row_data, previous);
+            self.edit_history.redo.push((id, current));
+            if !self.batching {
+                self.recreate_rows_no_unselect();
+            }
+            return true;
+        }
+        false
+    }
+
+    /// Applies the latest edit reverted with [`undo_edit`](#method.undo_edit) again.
+    ///
+    /// # Returns:
+    /// - `bool`: `true` if an edit was applied again.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.redo_edit();
+    /// ```
+    pub fn redo_edit(&mut self) -> bool {
+        while let Some((id, next)) = self.edit_history.redo.pop() {
+            let Some(row) = self.rows.get_mut(&id) else {
+                continue;
+            };
+            let current = std::mem::replace(&mut row.row_data, next);
+            self.edit_history.undo.push_back((id, current));
+            if !self.batching {
+                self.recreate_rows_no_unselect();
+            }
+            return true;
+        }
+        false
+    }
+
+    /// Sets the maximum number of edits that can be undone. The oldest edits are dropped once
+    /// the limit is reached. Default: 100
+    ///
+    /// # Parameters:
+    /// - `depth`: The maximum number of edits to keep. `0` disables undo.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_edit_history_depth(20);
+    /// ```
+    pub fn set_edit_history_depth(&mut self, depth: usize) {
+        let history = &mut self.edit_history;
+        history.depth = depth;
+        while history.undo.len() > depth {
+            history.undo.pop_front();
+        }
+    }
+
+    /// Removes every edit that can be undone or redone.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.clear_edit_history();
+    /// ```
+    pub fn clear_edit_history(&mut self) {
+        self.edit_history.undo.clear();
+        self.edit_history.redo.clear();
+    }
+}
@@ -0,0 +1,66 @@
+use std::hash::Hash;
+use std::io::{Result, Write};
+
+use crate::copy_format::csv_field;
+use crate::{ColumnOperations, ColumnOrdering, SelectableTable};
+
+/// Functions related to exporting the rows of the table
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Writes the displayed rows as comma separated values to the writer.
+    ///
+    /// Each row is written as soon as it is formatted, so the whole output is never held in
+    /// memory. Useful for exporting very large tables to a file. Rows are written in display
+    /// order.
+    ///
+    /// # Parameters:
+    /// - `writer`: The writer to write the values to, such as a `BufWriter<File>`.
+    /// - `selected_only`: `true` to only write the selected rows and the columns with a
+    ///   selection. Cells that are not selected are written empty.
+    ///
+    /// # Errors
+    /// Returns the first error returned by the writer.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let mut writer = BufWriter::new(File::create("rows.csv")?);
+    /// table.export_csv(&mut writer, false)?;
+    /// ```
+    pub fn export_csv<W: Write>(&self, writer: &mut W, selected_only: bool) -> Result<()> {
+        let columns: Vec<&F> = self
+            .all_columns
+            .iter()
+            .filter(|column| !selected_only || self.active_columns.contains(column))
+            .collect();
+
+        for row in &self.formatted_rows {
+            if selected_only && row.selected_columns.is_empty() {
+                continue;
+            }
+
+            for (index, column) in columns.iter().enumerate() {
+                if index != 0 {
+                    writer.write_all(b",")?;
+                }
+                if !selected_only || row.selected_columns.contains(*column) {
+                    let text = csv_field(column.column_text(&row.row_data));
+                    writer.write_all(text.as_bytes())?;
+                }
+            }
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()
+    }
+}
@@ -0,0 +1,108 @@
+use egui::Color32;
+use std::hash::Hash;
+use std::time::Duration;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableTable};
+
+#[derive(Default)]
+pub struct FollowTail {
+    pub enabled: bool,
+    /// Color and duration to flash the newest row with when it is scrolled into view
+    pub flash: Option<(Color32, Duration)>,
+    /// The newest row that was added and is yet to be scrolled into view
+    pub pending_row: Option<i64>,
+}
+
+/// Enables or configures following the newest row in the table view.
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Scroll to the newest row once it is displayed, if the table was scrolled to the bottom
+    /// in the last frame
+    pub(crate) fn follow_newest_row(&mut self) {
+        if !self.follow_tail.enabled {
+            return;
+        }
+        let Some(id) = self.follow_tail.pending_row else {
+            return;
+        };
+        // Wait till the rows are recreated and the new row is displayed
+        if !self.indexed_ids.contains_key(&id) {
+            return;
+        }
+        self.follow_tail.pending_row = None;
+
+        // Only follow while the user has not scrolled away from the bottom
+        // The content height of the last frame accounts for resized rows
+        let row_step = self.row_height + self.row_spacing;
+        let at_bottom =
+            self.scroll_offset.y + self.view_height >= self.last_content_height - row_step;
+        if !at_bottom {
+            return;
+        }
+
+        self.scroll_to_index = Some(self.formatted_rows.len().saturating_sub(1));
+        if let Some((color, duration)) = self.follow_tail.flash {
+            self.flash_row(id, color, duration);
+        }
+    }
+
+    /// Keeps the newest row in view by scrolling to the bottom whenever a new row is added and
+    /// displayed, as long as the table was already scrolled to the bottom.
+    ///
+    /// Scrolling away from the bottom pauses following until the table is scrolled back to the
+    /// bottom. Works best with [`no_sort`](#method.no_sort) where new rows are displayed last.
+    ///
+    /// # Returns:
+    /// An updated instance of the table with tail following enabled.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .no_sort().auto_follow_tail();
+    /// ```
+    #[must_use]
+    pub const fn auto_follow_tail(mut self) -> Self {
+        self.follow_tail.enabled = true;
+        self
+    }
+
+    /// Sets whether the table should follow the newest row.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to follow the newest row, `false` to disable it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_auto_follow_tail(true);
+    /// ```
+    pub const fn set_auto_follow_tail(&mut self, status: bool) {
+        self.follow_tail.enabled = status;
+        self.follow_tail.pending_row = None;
+    }
+
+    /// Sets whether the newest row should be flashed when it is scrolled into view by
+    /// [`auto_follow_tail`](#method.auto_follow_tail).
+    ///
+    /// # Parameters:
+    /// - `flash`: The color and duration of the flash, or `None` to disable it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_auto_follow_tail_flash(Some((Color32::YELLOW, Duration::from_millis(500))));
+    /// ```
+    pub const fn set_auto_follow_tail_flash(&mut self, flash: Option<(Color32, Duration)>) {
+        self.follow_tail.flash = flash;
+    }
+}
@@ -1,15 +1,99 @@
+mod alignment;
 mod auto_reload;
 mod auto_scroll;
+mod batch;
+mod collation;
+mod copy_format;
+mod detail_rows;
+mod dirty_rows;
+mod display_order;
+mod edit_history;
+mod export;
+mod follow_tail;
+mod natural_order;
+mod row_flash;
+mod row_meta;
+mod row_resize;
 mod row_selection;
+mod row_visibility;
+mod search;
+mod selection_behavior;
+mod selection_column;
+mod shortcuts;
+mod type_ahead;
+mod view_snapshot;
 
+use alignment::aligned_layout;
 use auto_reload::AutoReload;
 pub use auto_scroll::AutoScroll;
+pub use collation::locale_cmp;
+pub use copy_format::{
+    AlignedFormatter, CopyFormatter, CsvFormatter, MarkdownFormatter, TsvFormatter,
+};
+use edit_history::EditHistory;
 use egui::ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
-use egui::{Event, Key, Label, Response, ScrollArea, Sense, Ui};
+use egui::{
+    Align, Color32, Label, Pos2, Rect, Response, ScrollArea, SelectableLabel, Sense, Stroke,
+    TextStyle, Ui, Vec2,
+};
 use egui_extras::{Column, TableBuilder, TableRow};
+use follow_tail::FollowTail;
+pub use natural_order::natural_cmp;
 use rayon::prelude::*;
+use row_flash::RowFlash;
+use row_resize::RowResize;
+pub use selection_behavior::{SelectionAction, SelectionBehavior};
+pub use shortcuts::Shortcuts;
+use std::any::Any;
 use std::cmp::Ordering;
 use std::hash::Hash;
+use std::ops::Range;
+use std::time::Duration;
+use type_ahead::TypeAhead;
+pub use view_snapshot::ViewSnapshot;
+
+/// Callback called with the column whose header was clicked
+type HeaderClickCallback<F> = Box<dyn FnMut(&F) + Send>;
+
+/// Custom function for calculating the auto-scroll amount during a drag
+type AutoScrollFn = Box<dyn Fn(Rect, Option<Pos2>, Duration) -> Option<f32> + Send>;
+
+/// Callback called at the start and end of sorting the rows
+type SortProgressCallback = Box<dyn FnMut(SortProgress) + Send>;
+
+/// Callback called with the ID of a newly added row
+type RowAddedCallback = Box<dyn FnMut(i64) + Send>;
+
+/// Callback called instead of selecting all cells when the select all shortcut is pressed
+type SelectAllCallback<Row, F, Conf> = Box<dyn FnMut(&mut SelectableTable<Row, F, Conf>) + Send>;
+
+/// Function creating the displayed version of a row
+type ViewTransformFn<Row> = dyn Fn(&Row) -> Row + Send + Sync;
+
+/// Callback for painting over the table with the rect of the table
+type OverlayCallback = Box<dyn FnMut(&mut Ui, Rect) + Send>;
+
+/// Callback for drawing the detail row of an expanded row
+type DetailCallback<Row, F> = Box<dyn FnMut(&mut Ui, &SelectableRow<Row, F>) + Send>;
+
+/// Callback for drawing the content of the serial column with the row number
+type SerialContentCallback<Row, F> = Box<dyn FnMut(&mut Ui, usize, &SelectableRow<Row, F>) + Send>;
+
+/// Sorts the slice with the comparator, in parallel unless `single_threaded` is set
+fn sort_slice<T, C>(data: &mut [T], single_threaded: bool, compare: C)
+where
+    T: Send,
+    C: Fn(&T, &T) -> Ordering + Sync,
+{
+    if single_threaded {
+        data.sort_unstable_by(compare);
+    } else {
+        data.par_sort_unstable_by(compare);
+    }
+}
+
+/// The maximum number of rows measured by [`SelectableTable::measure_column`]
+pub const MEASURE_ROW_LIMIT: usize = 1000;
 
 /// Enum representing the possible sort orders for table columns.
 #[derive(Default, Clone, Copy)]
@@ -21,6 +105,68 @@ pub enum SortOrder {
     Descending,
 }
 
+/// Enum representing the stage of recreating the displayed rows, passed to the callback set with
+/// [`SelectableTable::set_on_sort_progress`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SortProgress {
+    /// Sorting has started. Contains the total number of rows in the table.
+    Started(usize),
+    /// Sorting has finished. Contains the number of rows that will be displayed.
+    Finished(usize),
+}
+
+/// Enum representing how many cells can be selected at the same time.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SelectionMode {
+    /// Any number of cells can be selected by dragging or with Ctrl held.
+    #[default]
+    Multi,
+    /// Only a single cell, or a single row with full row selection, can be selected. Any new
+    /// selection replaces the previous one and dragging moves the selection.
+    Single,
+}
+
+/// Enum representing presets for the row height, header height and cell spacing of the table.
+///
+/// The values of each preset:
+/// - `Comfortable`: row height `32.0`, header height `26.0`, cell spacing `10.0 x 6.0`
+/// - `Standard`: row height `25.0`, header height `20.0`, cell spacing of the current style
+/// - `Compact`: row height `18.0`, header height `16.0`, cell spacing `4.0 x 1.0`
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Density {
+    /// Taller rows with more space between cells.
+    Comfortable,
+    /// The default row and header height with the spacing of the current style.
+    #[default]
+    Standard,
+    /// Shorter rows with less space between cells.
+    Compact,
+}
+
+impl Density {
+    /// Returns the row height, header height and cell spacing of the preset
+    const fn values(self) -> (f32, f32, Option<Vec2>) {
+        match self {
+            Self::Comfortable => (32.0, 26.0, Some(Vec2::new(10.0, 6.0))),
+            Self::Standard => (25.0, 20.0, None),
+            Self::Compact => (18.0, 16.0, Some(Vec2::new(4.0, 1.0))),
+        }
+    }
+}
+
+/// Enum representing how much of the currently displayed rows are selected.
+///
+/// Useful for driving a tri-state "select all" checkbox in the header.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SelectionState {
+    /// None of the displayed rows have any selected column.
+    None,
+    /// Some, but not all, of the displayed rows have at least one selected column.
+    Some,
+    /// Every displayed row has at least one selected column.
+    All,
+}
+
 /// Trait for defining how to order rows based on a specific column.
 ///
 /// This trait should be implemented by users to specify how rows should be
@@ -63,6 +209,23 @@ where
     /// # Returns
     /// * `Ordering` - Indicates the relative order between the two rows.
     fn order_by(&self, row_1: &Row, row_2: &Row) -> Ordering;
+
+    /// Extract a key of the row that orders the same way as `order_by` for this column.
+    ///
+    /// When a key is returned, it is computed once per row while sorting and the rows are
+    /// ordered by comparing the keys instead of calling `order_by` for each comparison. Useful
+    /// when the ordering needs an expensive computation such as parsing or lowercasing. The key
+    /// type is shared by every column, so return `None` for the columns that should keep using
+    /// `order_by`. Should return either `Some` or `None` for every row of a column.
+    ///
+    /// # Arguments
+    /// * `row` - The row to extract the key from.
+    ///
+    /// # Returns
+    /// * `Option<impl Ord + Send + Sync>` - The sort key of the row, or `None` to use `order_by`.
+    fn sort_key(&self, _row: &Row) -> Option<impl Ord + Send + Sync> {
+        Option::<()>::None
+    }
 }
 
 /// Trait for defining column-specific operations in a table UI.
@@ -148,6 +311,39 @@ where
     /// # Returns
     /// * `String` - The text representation of this column for the row.
     fn column_text(&self, row: &Row) -> String;
+
+    /// Extract the text of the column that is searched in for the given row.
+    ///
+    /// Used by the column filters and searches instead of `column_text`, so the searchable
+    /// content can differ from what is displayed, such as searching a user by both the displayed
+    /// name and the hidden email. Defaults to `column_text`.
+    ///
+    /// # Arguments
+    /// * `row` - A reference to the row from which to extract the search text.
+    ///
+    /// # Returns
+    /// * `String` - The text to search in for this column of the row.
+    fn search_text(&self, row: &Row) -> String {
+        self.column_text(row)
+    }
+}
+
+/// Trait for providing every column of the table in the order they should be displayed.
+///
+/// Implementing this trait allows creating a table with
+/// [`SelectableTable::with_all_columns`] instead of collecting the columns manually.
+///
+/// # Example
+/// ```rust,ignore
+/// impl AllColumns for ColumnName {
+///     fn all_columns() -> Vec<Self> {
+///         vec![ColumnName::UserID, ColumnName::Name, ColumnName::Username]
+///     }
+/// }
+/// ```
+pub trait AllColumns: Sized {
+    /// Returns every column in the order they should be displayed.
+    fn all_columns() -> Vec<Self>;
 }
 
 /// Represents a row in a table with selectable columns.
@@ -180,7 +376,7 @@ where
 /// * `Row` - The type representing each row in the table.
 /// * `F` - A type used to identify columns, often an enum or field type.
 /// * `Conf` - Configuration type for additional table settings passed by the user. This is made available anytime
-///    when creating or modifying rows
+///   when creating or modifying rows
 pub struct SelectableTable<Row, F, Conf>
 where
     Row: Clone + Send + Sync,
@@ -199,6 +395,10 @@ where
     all_columns: Vec<F>,
     /// Maps each column to its index in the table for quick lookup.
     column_number: HashMap<F, usize>,
+    /// The `egui_extras` columns added to the `TableBuilder` before the `show_ui` closure runs
+    column_specs: Vec<Column>,
+    /// Columns that are part of the table but not displayed
+    hidden_columns: HashSet<F>,
     /// Stores all rows in the table, keyed by their unique ID.
     rows: HashMap<i64, SelectableRow<Row, F>>,
     /// The current set of formatted rows for display.
@@ -207,6 +407,26 @@ where
     sorted_by: F,
     /// The current sort order (ascending or descending).
     sort_order: SortOrder,
+    /// The column used to order rows that are equal in the sorted column
+    tiebreaker: Option<F>,
+    /// Whether sorting and searching run on the current thread instead of the rayon pool
+    single_threaded: bool,
+    /// Whether moving past the first or last row continues from the other end
+    navigation_wrap: bool,
+    /// Whether the sort is changed by double clicking on a header instead of a single click
+    sort_on_double_click: bool,
+    /// Whether clicking on a header only changes the sort without recreating the rows
+    defer_header_sort: bool,
+    /// Whether the sort was changed by a header click and the rows are yet to be recreated
+    sort_pending: bool,
+    /// Whether each column remembers the sort order it was last sorted with
+    remember_sort_direction: bool,
+    /// The last sort order of each column that was sorted
+    sort_directions: HashMap<F, SortOrder>,
+    /// Whether rows are sorted by a column. Rows are shown in insertion order otherwise
+    sorting_enabled: bool,
+    /// Called at the start and end of sorting the rows
+    on_sort_progress: Option<SortProgressCallback>,
     /// Tracks where a drag operation started in the table, if any.
     drag_started_on: Option<(i64, F)>,
     /// The columns that have at least 1 row with the column as selected
@@ -217,26 +437,178 @@ where
     last_active_row: Option<i64>,
     /// The last column where the pointer was
     last_active_column: Option<F>,
+    /// The cell a SHIFT click selection range starts from
+    selection_anchor: Option<(i64, F)>,
     /// Whether the pointer moved from the dragged point at least once
     beyond_drag_point: bool,
+    /// The selection before the ongoing subtractive drag started, if one is ongoing
+    subtract_drag_base: Option<HashMap<i64, HashSet<F>>>,
     /// Map of the row IDs to the indices of `formatted_rows`
     indexed_ids: HashMap<i64, usize>,
     /// The last ID that was used for a new row in the table.
     last_id_used: i64,
     /// Handles auto scroll operation when dragging
     auto_scroll: AutoScroll,
+    /// Custom function used instead of `auto_scroll` for calculating the scroll during a drag
+    auto_scroll_fn: Option<AutoScrollFn>,
+    /// The time the pointer started being near the edge of the table during a drag
+    edge_hover_since: Option<f64>,
     /// Handles auto recreating the displayed rows with the latest data
     auto_reload: AutoReload,
     /// Whether to select the entire row when dragging and selecting instead of a single cell
     select_full_row: bool,
+    /// Whether drag selection is constrained to the row where the drag started
+    single_row_drag: bool,
+    /// Whether dragging with the secondary button adds to the existing selection
+    right_drag_adds_selection: bool,
+    /// How long the pointer must be held down before a drag starts selecting cells
+    drag_delay: Duration,
+    /// The time the primary pointer button was last pressed
+    press_started_at: Option<f64>,
+    /// Whether multiple cells can be selected at the same time
+    selection_mode: SelectionMode,
+    /// The maximum number of rows that can be selected at the same time
+    max_selection: Option<usize>,
+    /// Whether the last selection stopped early because of the selection limit
+    selection_truncated: bool,
+    /// Whether pressing on a cell of a multi-selection keeps the selection until the pointer is
+    /// released without dragging
+    click_preserves_multiselect: bool,
+    /// Whether copying includes every cell within the bounding rectangle of the selection
+    copy_rectangular_fill: bool,
+    /// Whether repeated values in a column are left empty in the copied text
+    copy_dedup: bool,
+    /// Builds the copied text from the selected cells
+    copy_formatter: Box<dyn CopyFormatter<Row, F, Conf> + Send>,
     /// Whether to add a horizontal scrollbar
     horizontal_scroll: bool,
+    /// Whether the last frame was rendered inside the horizontal scroll area
+    rendered_horizontal: bool,
     /// Additional Parameters passed by you, available when creating new rows or header. Can
     /// contain anything implementing the `Default` trait
     pub config: Conf,
+    /// Transient value available only while rendering the next frame
+    frame_context: Option<Box<dyn Any + Send>>,
+    /// View specific values attached to each row
+    row_meta: HashMap<i64, Box<dyn Any + Send>>,
 
     /// Whether to add the row serial column to the table
     add_serial_column: bool,
+    /// Whether to add a leading column with a checkbox for selecting each row
+    add_selection_column: bool,
+    /// Whether the row numbers count down when sorted in descending order
+    serial_reverse: bool,
+    /// The horizontal alignment of the row numbers in the serial column
+    serial_column_alignment: Align,
+    /// Draws the content of the serial column instead of the row number
+    serial_content: Option<SerialContentCallback<Row, F>>,
+    /// The horizontal alignment of each column
+    column_alignment: HashMap<F, Align>,
+    /// Columns where clicking on the header will not change the sorting
+    unsortable_columns: HashSet<F>,
+    /// Columns where the cells do not get the forced drag sense and are not drag selectable
+    interactive_columns: HashSet<F>,
+    /// Row IDs that are always displayed at the top of the table in the given order
+    pinned_rows: Vec<i64>,
+    /// Row IDs that are left out of the displayed rows
+    hidden_rows: HashSet<i64>,
+    /// Selected columns of the hidden rows, restored once the row is shown again
+    hidden_selection: HashMap<i64, HashSet<F>>,
+    /// IDs of the rows in the order of the last sort, including the hidden rows
+    sorted_ids: Vec<i64>,
+    /// Custom order of row IDs that is used instead of sorting when the rows are recreated
+    sticky_order: Option<Vec<i64>>,
+    /// Order of row IDs when the sort was frozen. Takes priority over the sticky order
+    frozen_order: Option<Vec<i64>>,
+    /// Lowercase filter text of each column. Only rows containing the text in the column are
+    /// displayed
+    column_filters: HashMap<F, String>,
+    /// Rows that matched the last highlight search with the score of their best match
+    matched_rows: HashMap<i64, u32>,
+    /// Whether selected rows that do not match the column filters are still displayed
+    keep_selected_visible: bool,
+    /// Called with the column whenever a header is clicked, before any sorting happens
+    on_header_click: Option<HeaderClickCallback<F>>,
+    /// Called with the ID of each newly added row
+    on_row_added: Option<RowAddedCallback>,
+    /// Whether changes are being made inside `batch`
+    batching: bool,
+    /// IDs of the rows added during the current batch
+    batched_row_ids: Vec<i64>,
+    /// Called instead of `select_all` when the select all shortcut is pressed
+    on_select_all: Option<SelectAllCallback<Row, F, Conf>>,
+    /// Callback for painting on top of the table after the body is drawn
+    on_overlay: Option<OverlayCallback>,
+    /// Creates the displayed version of each row without modifying the stored rows
+    view_transform: Option<Box<ViewTransformFn<Row>>>,
+    /// Whether to paint a rectangle over the cells being selected by an ongoing drag
+    draw_selection_rect: bool,
+    /// Whether to paint the selection background behind the selected cells
+    uniform_selection_style: bool,
+    /// The rect of the cell where the drag started, if it was rendered in this frame
+    drag_anchor_rect: Option<Rect>,
+    /// The row ID and the rect of the cell under the pointer during an ongoing drag
+    drag_hover_cell: Option<(i64, Rect)>,
+    /// The height of each row in the table body
+    row_height: f32,
+    /// Handles resizing individual rows by dragging their bottom edge
+    row_resize: RowResize,
+    /// The rows that show a detail row below them
+    expanded_rows: HashSet<i64>,
+    /// The height of each detail row
+    detail_height: f32,
+    /// Callback for drawing the detail row of an expanded row
+    on_detail: Option<DetailCallback<Row, F>>,
+    /// The screen rect of the visible table body as of the last frame
+    body_rect: Rect,
+    /// Whether to scroll back to the selected row when the table is resized
+    keep_selection_visible_on_resize: bool,
+    /// The size of the area given to the table in the last frame
+    last_table_size: Option<Vec2>,
+    /// Screen rect of each cell rendered in the last frame
+    cell_rects: HashMap<(i64, F), Rect>,
+    /// The row ID and column of the cell under the pointer in the last frame
+    hovered_cell: Option<(i64, F)>,
+    /// The maximum height of the table body before it scrolls
+    max_height: Option<f32>,
+    /// The height of the header row
+    header_height: f32,
+    /// Whether to paint a shadow below the header while the body is scrolled
+    header_shadow: bool,
+    /// The spacing between cells. Uses the spacing of the current style if `None`
+    cell_spacing: Option<Vec2>,
+    /// The vertical spacing between rows as of the last frame
+    row_spacing: f32,
+    /// The height of the visible table body as of the last frame
+    view_height: f32,
+    /// The horizontal and vertical scroll offset of the table as of the last frame
+    scroll_offset: Vec2,
+    /// The rect of the vertical scrollbar as of the last frame, if the body was scrollable
+    scrollbar_rect: Option<Rect>,
+    /// Whether the pointer is over the vertical scrollbar in the current frame
+    pointer_over_scrollbar: bool,
+    /// The height of the body content as of the last frame
+    last_content_height: f32,
+    /// Display index of the row to scroll to in the next frame
+    scroll_to_index: Option<usize>,
+    /// Scroll offset to apply in the next frame
+    scroll_to_offset: Option<Vec2>,
+    /// Handles scrolling to the newest row when it is added
+    follow_tail: FollowTail,
+    /// Keyboard shortcuts for selecting all cells and copying
+    shortcuts: Shortcuts,
+    /// Which modifiers add to or remove from the selection on click or drag
+    selection_behavior: SelectionBehavior,
+    /// Previous data of the edited rows for undo and redo
+    edit_history: EditHistory<Row>,
+    /// Handles jumping to a row by typing the start of its text
+    type_ahead: TypeAhead,
+    /// Rows that are temporarily highlighted with a fading background
+    flashing_rows: HashMap<i64, RowFlash>,
+    /// Row IDs marked as modified
+    dirty_rows: HashSet<i64>,
+    /// Background color painted behind the modified rows
+    dirty_row_tint: Option<Color32>,
 }
 
 impl<Row, F, Conf> SelectableTable<Row, F, Conf>
@@ -257,6 +629,7 @@ where
     ///
     /// # Parameters:
     /// - `columns`: A `Vec<F>` representing the columns. Columns must be passed in the correct order (e.g., 1 to 10).
+    ///   Must contain at least one column, which is asserted in debug builds.
     ///
     /// # Returns:
     /// - A new instance of `SelectableTable`.
@@ -266,7 +639,13 @@ where
     /// let table = SelectableTable::new(vec![col1, col2, col3]);
     /// ```
     #[must_use]
+    #[allow(clippy::too_many_lines)]
     pub fn new(columns: Vec<F>) -> Self {
+        debug_assert!(
+            !columns.is_empty(),
+            "SelectableTable requires at least one column"
+        );
+
         let all_columns = columns.clone();
         let mut column_number = HashMap::new();
 
@@ -276,24 +655,111 @@ where
         Self {
             all_columns,
             column_number,
+            column_specs: Vec::new(),
+            hidden_columns: HashSet::new(),
             last_id_used: 0,
             rows: HashMap::new(),
             formatted_rows: Vec::new(),
             sorted_by: F::default(),
+            tiebreaker: None,
+            single_threaded: false,
+            navigation_wrap: false,
             sort_order: SortOrder::default(),
+            sort_on_double_click: false,
+            defer_header_sort: false,
+            sort_pending: false,
+            remember_sort_direction: false,
+            sort_directions: HashMap::new(),
+            sorting_enabled: true,
+            on_sort_progress: None,
             drag_started_on: None,
             active_columns: HashSet::new(),
             active_rows: HashSet::new(),
             last_active_row: None,
             last_active_column: None,
+            selection_anchor: None,
             beyond_drag_point: false,
+            subtract_drag_base: None,
             indexed_ids: HashMap::new(),
             auto_scroll: AutoScroll::default(),
+            auto_scroll_fn: None,
+            edge_hover_since: None,
             auto_reload: AutoReload::default(),
             select_full_row: false,
+            single_row_drag: false,
+            right_drag_adds_selection: false,
+            drag_delay: Duration::ZERO,
+            press_started_at: None,
+            selection_mode: SelectionMode::default(),
+            max_selection: None,
+            selection_truncated: false,
+            click_preserves_multiselect: false,
+            copy_rectangular_fill: false,
+            copy_dedup: false,
+            copy_formatter: Box::new(AlignedFormatter),
             horizontal_scroll: false,
+            rendered_horizontal: false,
             config: Conf::default(),
+            frame_context: None,
+            row_meta: HashMap::new(),
             add_serial_column: false,
+            add_selection_column: false,
+            serial_reverse: false,
+            serial_column_alignment: Align::Center,
+            serial_content: None,
+            column_alignment: HashMap::new(),
+            unsortable_columns: HashSet::new(),
+            interactive_columns: HashSet::new(),
+            pinned_rows: Vec::new(),
+            hidden_rows: HashSet::new(),
+            hidden_selection: HashMap::new(),
+            sorted_ids: Vec::new(),
+            sticky_order: None,
+            frozen_order: None,
+            column_filters: HashMap::new(),
+            keep_selected_visible: false,
+            matched_rows: HashMap::new(),
+            on_header_click: None,
+            on_row_added: None,
+            on_select_all: None,
+            on_overlay: None,
+            batching: false,
+            batched_row_ids: Vec::new(),
+            view_transform: None,
+            draw_selection_rect: false,
+            uniform_selection_style: false,
+            drag_anchor_rect: None,
+            drag_hover_cell: None,
+            row_height: 25.0,
+            row_resize: RowResize::default(),
+            expanded_rows: HashSet::new(),
+            detail_height: 100.0,
+            on_detail: None,
+            body_rect: Rect::NOTHING,
+            header_height: 20.0,
+            header_shadow: false,
+            max_height: None,
+            cell_rects: HashMap::new(),
+            hovered_cell: None,
+            keep_selection_visible_on_resize: false,
+            last_table_size: None,
+            cell_spacing: None,
+            row_spacing: 0.0,
+            view_height: 0.0,
+            scroll_offset: Vec2::ZERO,
+            scrollbar_rect: None,
+            pointer_over_scrollbar: false,
+            last_content_height: 0.0,
+            scroll_to_index: None,
+            scroll_to_offset: None,
+            follow_tail: FollowTail::default(),
+            type_ahead: TypeAhead::default(),
+            shortcuts: Shortcuts::default(),
+            selection_behavior: SelectionBehavior::default(),
+            edit_history: EditHistory::default(),
+            flashing_rows: HashMap::new(),
+            dirty_rows: HashSet::new(),
+            dirty_row_tint: None,
         }
     }
 
@@ -329,22 +795,114 @@ where
         self
     }
 
-    /// Clears all rows from the table, including the displayed ones
+    /// Clears the displayed rows and the selection without touching the stored rows.
+    ///
+    /// The table shows no rows until [`recreate_rows`](#method.recreate_rows) is called, which
+    /// rebuilds the display from the preserved rows. Unlike
+    /// [`clear_all_rows`](#method.clear_all_rows), no row data or IDs are lost.
     ///
     /// # Example:
     /// ```rust,ignore
-    /// table.clear_all_rows();
+    /// table.clear_display();
+    /// // later
+    /// table.recreate_rows();
     /// ```
-    pub fn clear_all_rows(&mut self) {
-        self.rows.clear();
+    pub fn clear_display(&mut self) {
         self.formatted_rows.clear();
+        self.indexed_ids.clear();
         self.active_rows.clear();
         self.active_columns.clear();
+        self.hidden_selection.clear();
+        self.sorted_ids.clear();
+        self.last_active_row = None;
+        self.last_active_column = None;
+        self.drag_started_on = None;
+        self.beyond_drag_point = false;
+    }
+
+    /// Sets a value that is available only while rendering the next frame.
+    ///
+    /// Unlike [`config`](#structfield.config), this is meant for ephemeral render inputs such as
+    /// the current search query for highlighting. The value can be retrieved with
+    /// [`frame_context`](#method.frame_context) inside `create_header` or `create_table_row` and is
+    /// cleared at the end of [`show_ui`](#method.show_ui).
+    ///
+    /// # Parameters:
+    /// - `context`: The value to make available for the next frame.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_frame_context(search_query.clone());
+    /// table.show_ui(ui, |builder| builder);
+    /// ```
+    pub fn set_frame_context<T: Any + Send>(&mut self, context: T) {
+        self.frame_context = Some(Box::new(context));
+    }
+
+    /// Returns the value set with [`set_frame_context`](#method.set_frame_context) for the current
+    /// frame, if one was set with the same type.
+    ///
+    /// # Returns:
+    /// - `Option<&T>`: The frame context if it is set and of type `T`.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// // Inside create_table_row
+    /// let query = table.frame_context::<String>();
+    /// ```
+    pub fn frame_context<T: Any>(&self) -> Option<&T> {
+        self.frame_context.as_ref()?.downcast_ref()
+    }
+
+    /// Clears all rows from the table, including the displayed ones. Row IDs start from 0 again
+    /// for the newly added rows.
+    ///
+    /// Use [`clear_all_rows_keep_ids`](#method.clear_all_rows_keep_ids) if the IDs of the
+    /// cleared rows are still referenced elsewhere.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.clear_all_rows();
+    /// ```
+    pub fn clear_all_rows(&mut self) {
+        self.clear_all_rows_keep_ids();
         self.last_id_used = 0;
     }
 
+    /// Clears all rows from the table, including the displayed ones, without resetting the row
+    /// IDs.
+    ///
+    /// Unlike [`clear_all_rows`](#method.clear_all_rows), newly added rows continue from the last
+    /// used ID, so they never reuse the ID of a cleared row. Useful when the IDs are mapped
+    /// externally and a recycled ID would alias a stale reference or selection.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.clear_all_rows_keep_ids();
+    /// ```
+    pub fn clear_all_rows_keep_ids(&mut self) {
+        self.rows.clear();
+        self.clear_display();
+        self.pinned_rows.clear();
+        self.hidden_rows.clear();
+        self.flashing_rows.clear();
+        self.row_resize.heights.clear();
+        self.row_meta.clear();
+        self.expanded_rows.clear();
+        self.dirty_rows.clear();
+        self.matched_rows.clear();
+        self.clear_edit_history();
+        self.follow_tail.pending_row = None;
+    }
+
     /// Displays the UI for the table and uses the provided `TableBuilder` for creating the table UI.
     ///
+    /// The closure must add exactly [`expected_column_count`](#method.expected_column_count)
+    /// columns minus the serial and selection columns, which are added internally. A mismatch
+    /// panics in debug builds, while release builds may render empty columns. Columns set with
+    /// [`set_columns`](#method.set_columns) are added before the closure runs and count towards
+    /// that number.
+    ///
     /// # Parameters:
     /// - `ui`: The UI context where the table will be rendered.
     /// - `table_builder`: A closure that receives and modifies the `TableBuilder`.
@@ -357,83 +915,303 @@ where
     where
         Fn: FnOnce(TableBuilder) -> TableBuilder,
     {
-        let is_ctrl_pressed = ui.ctx().input(|i| i.modifiers.ctrl);
-        let key_a_pressed = ui.ctx().input(|i| i.key_pressed(Key::A));
-        let copy_initiated = ui.ctx().input(|i| i.events.contains(&Event::Copy));
+        let select_all_pressed = ui.input(|i| self.shortcuts.select_all_pressed(i));
+        let copy_initiated = ui.input(|i| self.shortcuts.copy_pressed(i));
+        // Text edits have their own undo, so leave the shortcuts to them while focused
+        let text_focused = ui.ctx().wants_keyboard_input();
+        let undo_pressed = !text_focused && ui.input(|i| self.shortcuts.undo_edit_pressed(i));
+        let redo_pressed = !text_focused && ui.input(|i| self.shortcuts.redo_edit_pressed(i));
         let ctx = ui.ctx().clone();
 
         if copy_initiated {
             self.copy_selected_cells(ui);
         }
-        if is_ctrl_pressed && key_a_pressed {
-            self.select_all();
+        if select_all_pressed {
+            if let Some(mut callback) = self.on_select_all.take() {
+                callback(self);
+                // The callback may have replaced itself
+                if self.on_select_all.is_none() {
+                    self.on_select_all = Some(callback);
+                }
+            } else {
+                self.select_all();
+            }
+        }
+        if undo_pressed {
+            self.undo_edit();
+        }
+        if redo_pressed {
+            self.redo_edit();
         }
 
         let pointer = ui.input(|i| i.pointer.hover_pos());
-        let max_rect = ui.max_rect();
+        self.pointer_over_scrollbar = self
+            .scrollbar_rect
+            .zip(pointer)
+            .is_some_and(|(rect, pos)| rect.contains(pos));
+        let mut max_rect = ui.max_rect();
+        // Auto scroll should trigger at the edges of the bounded table, not the parent UI
+        if let Some(max_height) = self.max_height {
+            max_rect.max.y = max_rect
+                .max
+                .y
+                .min(max_rect.min.y + self.header_height + max_height);
+        }
 
-        if self.horizontal_scroll {
-            ScrollArea::horizontal().show(ui, |ui| {
-                let mut table = TableBuilder::new(ui);
+        self.drag_anchor_rect = None;
+        self.drag_hover_cell = None;
+        self.cell_rects.clear();
+        self.hovered_cell = None;
 
-                if self.add_serial_column {
-                    table = table.column(Column::initial(25.0).clip(true));
-                }
+        self.follow_newest_row();
+        self.handle_type_ahead(ui);
 
-                table = table_builder(table);
+        let time = ui.input(|i| i.time);
+        if self.update_row_flashes(time) {
+            ctx.request_repaint();
+        }
 
-                if self.drag_started_on.is_some() {
-                    if let Some(offset) = self.auto_scroll.start_scroll(max_rect, pointer) {
-                        table = table.vertical_scroll_offset(offset);
-                        ctx.request_repaint();
-                    }
-                };
+        let original_spacing = ui.spacing().item_spacing;
+        if let Some(spacing) = self.cell_spacing {
+            ui.spacing_mut().item_spacing = spacing;
+        }
+        self.row_spacing = ui.spacing().item_spacing.y;
 
-                let output = table
-                    .header(20.0, |header| {
-                        self.build_head(header);
-                    })
-                    .body(|body| {
-                        body.rows(25.0, self.formatted_rows.len(), |row| {
-                            let index = row.index();
-                            self.build_body(row, index);
-                        });
-                    });
-                let scroll_offset = output.state.offset.y;
-                self.update_scroll_offset(scroll_offset);
+        self.rendered_horizontal = self.horizontal_scroll;
+        if self.horizontal_scroll {
+            let mut scroll_area = ScrollArea::horizontal();
+            if let Some(offset) = self.scroll_to_offset {
+                scroll_area = scroll_area.horizontal_scroll_offset(offset.x);
+            }
+            let output = scroll_area.show(ui, |ui| {
+                self.show_table(ui, table_builder, max_rect, pointer, time);
             });
+            self.scroll_offset.x = output.state.offset.x;
         } else {
-            let mut table = TableBuilder::new(ui);
+            self.show_table(ui, table_builder, max_rect, pointer, time);
+        }
 
-            if self.add_serial_column {
-                table = table.column(Column::initial(25.0).clip(true));
+        if self.keep_selection_visible_on_resize {
+            let resized = self
+                .last_table_size
+                .is_some_and(|size| size != max_rect.size());
+            self.last_table_size = Some(max_rect.size());
+            if resized && self.scroll_to_selected_if_hidden() {
+                ctx.request_repaint();
             }
+        }
 
-            table = table_builder(table);
+        ui.spacing_mut().item_spacing = original_spacing;
+        self.frame_context = None;
+    }
 
-            if self.drag_started_on.is_some() {
-                if let Some(offset) = self.auto_scroll.start_scroll(max_rect, pointer) {
-                    table = table.vertical_scroll_offset(offset);
-                    ctx.request_repaint();
-                }
-            };
+    /// Displays the UI for the table using the columns set with
+    /// [`set_columns`](#method.set_columns).
+    ///
+    /// Same as [`show_ui`](#method.show_ui) with a closure that leaves the `TableBuilder` as is.
+    ///
+    /// # Parameters:
+    /// - `ui`: The UI context where the table will be rendered.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_columns(vec![Column::initial(150.0); 3]);
+    /// // Every frame
+    /// table.show(ui);
+    /// ```
+    pub fn show(&mut self, ui: &mut Ui) {
+        self.show_ui(ui, |builder| builder);
+    }
 
-            let output = table
-                .header(20.0, |header| {
-                    self.build_head(header);
-                })
-                .body(|body| {
-                    body.rows(25.0, self.formatted_rows.len(), |row| {
+    /// Sets the `egui_extras` columns of the table, added to the `TableBuilder` each frame
+    /// before the [`show_ui`](#method.show_ui) closure runs.
+    ///
+    /// Keeps the column setup in one place instead of rebuilding it in the closure every frame.
+    /// There should be one column for each displayed table column, in display order, without
+    /// the serial column which is added internally. Update it along with
+    /// [`set_columns_layout`](#method.set_columns_layout) when columns are hidden or reordered.
+    ///
+    /// # Parameters:
+    /// - `columns`: The columns to add to the `TableBuilder`.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_columns(vec![
+    ///     Column::initial(150.0),
+    ///     Column::initial(100.0).clip(true),
+    ///     Column::remainder(),
+    /// ]);
+    /// ```
+    pub fn set_columns(&mut self, columns: Vec<Column>) {
+        self.column_specs = columns;
+    }
+
+    /// Builds the table with its header and body. Shared between the table with and without the
+    /// horizontal scrollbar
+    fn show_table<Fn>(
+        &mut self,
+        ui: &mut Ui,
+        table_builder: Fn,
+        max_rect: Rect,
+        pointer: Option<Pos2>,
+        time: f64,
+    ) where
+        Fn: FnOnce(TableBuilder) -> TableBuilder,
+    {
+        let ctx = ui.ctx().clone();
+        let table_start = ui.cursor().min;
+        let mut table = TableBuilder::new(ui);
+
+        if self.add_selection_column {
+            table = table.column(Column::exact(20.0));
+        }
+
+        if self.add_serial_column {
+            table = table.column(Column::initial(25.0).clip(true));
+        }
+
+        if let Some(max_height) = self.max_height {
+            table = table.max_scroll_height(max_height);
+        }
+
+        for column in &self.column_specs {
+            table = table.column(*column);
+        }
+
+        table = table_builder(table);
+
+        // Grabbing the scrollbar already scrolls the table, auto scroll would only fight it
+        if self.drag_started_on.is_some() && !self.pointer_over_scrollbar {
+            if let Some(offset) = self.drag_scroll_offset(max_rect, pointer, time) {
+                table = table.vertical_scroll_offset(offset);
+                ctx.request_repaint();
+            }
+        }
+
+        if let Some(offset) = self.scroll_to_offset.take() {
+            table = table.vertical_scroll_offset(offset.y);
+        }
+
+        if let Some(index) = self.scroll_to_index.take() {
+            table = table.scroll_to_row(self.body_index(index), None);
+        }
+
+        let output = table
+            .header(self.header_height, |header| {
+                self.build_head(header);
+            })
+            .body(|body| {
+                // The builder does not expose its columns, but there is one width per column
+                debug_assert_eq!(
+                    body.widths().len(),
+                    self.expected_column_count(),
+                    "The table builder must have one column for each of the table columns"
+                );
+                if self.row_resize.heights.is_empty() && self.expanded_rows.is_empty() {
+                    body.rows(self.row_height, self.formatted_rows.len(), |row| {
                         let index = row.index();
                         self.build_body(row, index);
                     });
-                });
-            let scroll_offset = output.state.offset.y;
-            self.update_scroll_offset(scroll_offset);
+                } else {
+                    let layout = self.body_layout();
+                    let heights = layout.iter().map(|body_row| body_row.height);
+                    body.heterogeneous_rows(heights, |row| {
+                        let body_row = layout[row.index()];
+                        if body_row.is_detail {
+                            self.build_detail(row, body_row.index);
+                        } else {
+                            self.build_body(row, body_row.index);
+                        }
+                    });
+                }
+            });
+        self.update_scroll_offset(output.state.offset.y);
+        self.scroll_offset = output.state.offset;
+        self.view_height = output.inner_rect.height();
+        self.body_rect = output.inner_rect;
+        self.update_scrollbar_rect(ui, output.inner_rect, output.content_size);
+        self.last_content_height = output.content_size.y;
+        self.paint_selection_rect(ui, max_rect);
+        if self.header_shadow && self.scroll_offset.y > 0.0 {
+            Self::paint_header_shadow(ui, output.inner_rect);
+        }
+
+        if let Some(callback) = self.on_overlay.as_mut() {
+            let table_rect = Rect::from_min_max(table_start, output.inner_rect.max);
+            callback(ui, table_rect);
+        }
+    }
+
+    /// Paints a shadow fading out from the top of the body to show the rows scrolled under the
+    /// header
+    fn paint_header_shadow(ui: &Ui, body_rect: Rect) {
+        const SHADOW_HEIGHT: u8 = 6;
+
+        let shadow_color = ui.visuals().window_shadow.color;
+        let painter = ui.painter().with_clip_rect(body_rect);
+        for step in 0..SHADOW_HEIGHT {
+            let fade = 1.0 - f32::from(step) / f32::from(SHADOW_HEIGHT);
+            let y = body_rect.min.y + f32::from(step);
+            let line = Rect::from_min_max(
+                Pos2::new(body_rect.min.x, y),
+                Pos2::new(body_rect.max.x, y + 1.0),
+            );
+            painter.rect_filled(line, 0.0, shadow_color.gamma_multiply(fade));
+        }
+    }
+
+    /// Paints a rectangle from the drag starting cell to the cell under the pointer during an
+    /// ongoing drag
+    fn paint_selection_rect(&self, ui: &Ui, max_rect: Rect) {
+        if !self.draw_selection_rect || !self.beyond_drag_point {
+            return;
+        }
+
+        let (Some((start_id, _)), Some((hover_id, hover_rect))) =
+            (self.drag_started_on.as_ref(), self.drag_hover_cell)
+        else {
+            return;
+        };
+
+        let mut rect = hover_rect;
+        if let Some(anchor_rect) = self.drag_anchor_rect {
+            rect = anchor_rect.union(hover_rect);
+        } else if self.indexed_ids.get(start_id) < self.indexed_ids.get(&hover_id) {
+            // The starting cell is not visible anymore. Extend the rect to the edge of the table
+            // in the direction of the starting row
+            rect.min.y = max_rect.min.y;
+        } else {
+            rect.max.y = max_rect.max.y;
+        }
+
+        let visuals = &ui.visuals().selection;
+        let painter = ui.painter().with_clip_rect(max_rect);
+        painter.rect_filled(rect, 0.0, visuals.bg_fill.gamma_multiply(0.3));
+        painter.rect_stroke(rect, 0.0, Stroke::new(1.0, visuals.stroke.color));
+    }
+
+    /// Saves the rect of the cell if it is relevant for painting the drag selection rectangle
+    fn track_drag_rect(&mut self, ui: &Ui, row_id: i64, column_name: &F) {
+        if !self.draw_selection_rect {
+            return;
+        }
+        let Some((start_id, start_column)) = self.drag_started_on.as_ref() else {
+            return;
+        };
+
+        let cell_rect = ui.max_rect();
+        if *start_id == row_id && start_column == column_name {
+            self.drag_anchor_rect = Some(cell_rect);
+        }
+        if ui.ui_contains_pointer() {
+            self.drag_hover_cell = Some((row_id, cell_rect));
         }
     }
 
     fn build_head(&mut self, mut header: TableRow) {
+        if self.add_selection_column {
+            header.col(|ui| self.selection_header_checkbox(ui));
+        }
         if self.add_serial_column {
             header.col(|ui| {
                 ui.add_sized(ui.available_size(), Label::new(""));
@@ -441,7 +1219,7 @@ where
         }
         for column_name in &self.all_columns.clone() {
             header.col(|ui| {
-                let sort_order = if &self.sorted_by == column_name {
+                let sort_order = if self.sorting_enabled && &self.sorted_by == column_name {
                     Some(self.sort_order)
                 } else {
                     None
@@ -455,24 +1233,107 @@ where
                 // for sorting, without click there won't be any actions.
 
                 if resp.clicked() {
+                    if let Some(callback) = self.on_header_click.as_mut() {
+                        callback(column_name);
+                    }
+                }
+
+                let sort_clicked = if self.sort_on_double_click {
+                    resp.double_clicked()
+                } else {
+                    resp.clicked()
+                };
+
+                if sort_clicked
+                    && self.sorting_enabled
+                    && !self.unsortable_columns.contains(column_name)
+                {
                     let is_selected = &self.sorted_by == column_name;
                     if is_selected {
                         self.change_sort_order();
                     } else {
                         self.change_sorted_by(column_name);
                     }
-                    self.recreate_rows();
+
+                    if self.defer_header_sort {
+                        self.sort_pending = true;
+                    } else {
+                        self.unselect_all();
+                        self.recreate_rows();
+                    }
                 }
             });
         }
     }
 
+    /// Creates a header filling the cell with the text and an arrow showing the sort order.
+    ///
+    /// Meant to be called from `create_header` for a consistent header look across columns. The
+    /// header is highlighted while the column is used for sorting and responds to clicks, so
+    /// clicking it changes the sorting like a custom header would.
+    ///
+    /// # Parameters:
+    /// - `ui`: The UI of the header cell.
+    /// - `text`: The text of the header.
+    /// - `sort_order`: The sort order passed to `create_header`.
+    ///
+    /// # Returns:
+    /// - `Response`: The response of the header.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// fn create_header(&self, ui: &mut Ui, sort_order: Option<SortOrder>, table: &mut SelectableTable<Row, F, Conf>) -> Option<Response> {
+    ///     Some(table.default_header(ui, "Name", sort_order))
+    /// }
+    /// ```
+    #[allow(clippy::unused_self)]
+    pub fn default_header(
+        &self,
+        ui: &mut Ui,
+        text: &str,
+        sort_order: Option<SortOrder>,
+    ) -> Response {
+        let text = match sort_order {
+            Some(SortOrder::Ascending) => format!("{text}🔽"),
+            Some(SortOrder::Descending) => format!("{text}🔼"),
+            None => text.to_string(),
+        };
+        ui.add_sized(
+            ui.available_size(),
+            SelectableLabel::new(sort_order.is_some(), text),
+        )
+    }
+
     fn build_body(&mut self, mut row: TableRow, index: usize) {
+        // Toggled before the row is cloned so the cells show the new selection in this frame
+        if self.add_selection_column {
+            row.col(|ui| self.selection_row_checkbox(ui, index));
+        }
         let row_data = self.formatted_rows[index].clone();
 
         if self.add_serial_column {
+            // Count down when sorted descending so the number reflects the rank
+            let serial = if self.serial_reverse
+                && self.sorting_enabled
+                && matches!(self.sort_order, SortOrder::Descending)
+            {
+                self.formatted_rows.len() - index
+            } else {
+                index + 1
+            };
             row.col(|ui| {
-                ui.add_sized(ui.available_size(), Label::new(format!("{}", index + 1)));
+                if let Some(callback) = self.serial_content.as_mut() {
+                    callback(ui, serial, &row_data);
+                    return;
+                }
+                let label = Label::new(format!("{serial}"));
+                if self.serial_column_alignment == Align::Center {
+                    ui.add_sized(ui.available_size(), label);
+                } else {
+                    ui.with_layout(aligned_layout(self.serial_column_alignment), |ui| {
+                        ui.add(label);
+                    });
+                }
             });
         }
         self.handle_table_body(row, &row_data);
@@ -519,15 +1380,32 @@ where
                 id: self.last_id_used,
                 selected_columns,
             };
-            to_return = Some(self.last_id_used);
+            let id = self.last_id_used;
+            to_return = Some(id);
+            self.follow_tail.pending_row = to_return;
             self.rows.insert(new_row.id, new_row);
             self.last_id_used += 1;
+
+            if self.batching {
+                self.batched_row_ids.push(id);
+            } else if let Some(callback) = self.on_row_added.as_mut() {
+                callback(id);
+            }
+        }
+
+        // The rows are recreated once at the end of the batch
+        if self.batching {
+            return to_return;
         }
 
         let reload = self.auto_reload.increment_count();
 
         if reload {
-            self.recreate_rows();
+            if self.auto_reload.keep_selection {
+                self.recreate_rows_no_unselect();
+            } else {
+                self.recreate_rows();
+            }
         }
         to_return
     }
@@ -559,208 +1437,1818 @@ where
         rows(&mut self.formatted_rows, &self.indexed_ids);
     }
 
+    /// Copies the data of every displayed row back into the stored rows.
+    ///
+    /// Changes made with [`modify_shown_row`](#method.modify_shown_row) only exist in the
+    /// displayed rows and are lost when the rows are recreated. Calling this after modifying
+    /// makes them persistent without also updating the rows through
+    /// [`add_modify_row`](#method.add_modify_row).
+    ///
+    /// Does nothing if a view transform is set with
+    /// [`set_view_transform`](#method.set_view_transform), as the displayed rows hold the
+    /// transformed data and committing it would apply the transform twice on the next
+    /// recreate. Panics in debug builds in that case.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.modify_shown_row(|formatted_rows, indexed_ids| {
+    ///     /* modify rows */
+    /// });
+    /// table.commit_shown_to_rows();
+    /// ```
+    pub fn commit_shown_to_rows(&mut self) {
+        debug_assert!(
+            self.view_transform.is_none(),
+            "commit_shown_to_rows cannot be used while a view transform is set"
+        );
+        if self.view_transform.is_some() {
+            return;
+        }
+
+        for row in &self.formatted_rows {
+            if let Some(stored_row) = self.rows.get_mut(&row.id) {
+                stored_row.row_data.clone_from(&row.row_data);
+            }
+        }
+    }
+
     /// Sort the rows to the current sorting order and column and save them for later reuse
     fn sort_rows(&mut self) {
-        let mut row_data: Vec<SelectableRow<Row, F>> =
-            self.rows.par_iter().map(|(_, v)| v.clone()).collect();
-
-        row_data.par_sort_by(|a, b| {
-            let ordering = self.sorted_by.order_by(&a.row_data, &b.row_data);
-            match self.sort_order {
-                SortOrder::Ascending => ordering,
-                SortOrder::Descending => ordering.reverse(),
-            }
-        });
+        self.sort_pending = false;
+        if let Some(callback) = self.on_sort_progress.as_mut() {
+            callback(SortProgress::Started(self.rows.len()));
+        }
 
-        let indexed_data = row_data
-            .par_iter()
-            .enumerate()
-            .map(|(index, row)| (row.id, index))
-            .collect();
+        let pinned_ids: HashSet<i64> = self.pinned_rows.iter().copied().collect();
+        let column_filters = &self.column_filters;
+        let view_transform = self.view_transform.as_deref();
+
+        let is_listed = |(id, row): &(&i64, &SelectableRow<Row, F>)| {
+            !pinned_ids.contains(*id) && Self::row_matches_filters(column_filters, &row.row_data)
+        };
+        let mut row_data: Vec<SelectableRow<Row, F>> = if self.single_threaded {
+            self.rows
+                .iter()
+                .filter(is_listed)
+                .map(|(_, v)| Self::view_row(view_transform, v))
+                .collect()
+        } else {
+            self.rows
+                .par_iter()
+                .filter(is_listed)
+                .map(|(_, v)| Self::view_row(view_transform, v))
+                .collect()
+        };
+
+        // Selected rows filtered out are displayed at the top so the selection stays in view
+        let mut kept_selected: Vec<SelectableRow<Row, F>> =
+            if self.keep_selected_visible && !column_filters.is_empty() {
+                self.active_rows
+                    .iter()
+                    .filter(|id| !pinned_ids.contains(id))
+                    .filter_map(|id| self.rows.get(id))
+                    .filter(|row| !Self::row_matches_filters(column_filters, &row.row_data))
+                    .map(|row| Self::view_row(view_transform, row))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+        kept_selected.sort_unstable_by_key(|row| row.id);
+
+        self.order_rows(&mut row_data);
+
+        if !kept_selected.is_empty() {
+            kept_selected.append(&mut row_data);
+            row_data = kept_selected;
+        }
+
+        // Pinned rows are placed at the top in the order they were pinned
+        if !self.pinned_rows.is_empty() {
+            let mut pinned_data: Vec<SelectableRow<Row, F>> = self
+                .pinned_rows
+                .iter()
+                .filter_map(|id| self.rows.get(id))
+                .filter(|row| Self::row_matches_filters(column_filters, &row.row_data))
+                .map(|row| Self::view_row(view_transform, row))
+                .collect();
+            pinned_data.append(&mut row_data);
+            row_data = pinned_data;
+        }
+
+        // Hidden rows keep their sorted position so they can be shown again without a re-sort
+        self.sorted_ids = row_data.iter().map(|row| row.id).collect();
+        if !self.hidden_rows.is_empty() {
+            row_data.retain(|row| !self.hidden_rows.contains(&row.id));
+        }
+
+        let indexed_data = if self.single_threaded {
+            row_data
+                .iter()
+                .enumerate()
+                .map(|(index, row)| (row.id, index))
+                .collect()
+        } else {
+            row_data
+                .par_iter()
+                .enumerate()
+                .map(|(index, row)| (row.id, index))
+                .collect()
+        };
 
         self.indexed_ids = indexed_data;
         self.formatted_rows = row_data;
+
+        // A newest row that was filtered out, hidden or removed is not followed once it is
+        // displayed later on
+        if self
+            .follow_tail
+            .pending_row
+            .is_some_and(|id| !self.indexed_ids.contains_key(&id))
+        {
+            self.follow_tail.pending_row = None;
+        }
+
+        if let Some(callback) = self.on_sort_progress.as_mut() {
+            callback(SortProgress::Finished(self.formatted_rows.len()));
+        }
     }
 
-    /// Change the current sort order from ascending to descending and vice versa. Will unselect
-    /// all selected rows
-    fn change_sort_order(&mut self) {
-        self.unselect_all();
+    /// Orders the rows by the frozen or custom order if set, otherwise by the sorted column or
+    /// the order they were added in
+    fn order_rows(&self, row_data: &mut Vec<SelectableRow<Row, F>>) {
+        if let Some(fixed_order) = self.frozen_order.as_ref().or(self.sticky_order.as_ref()) {
+            // Rows keep their frozen or custom position while new rows are appended in the order
+            // they were added
+            let positions: HashMap<i64, usize> = fixed_order
+                .iter()
+                .enumerate()
+                .map(|(index, id)| (*id, index))
+                .collect();
+            let position = |row: &SelectableRow<Row, F>| {
+                (
+                    positions.get(&row.id).copied().unwrap_or(usize::MAX),
+                    row.id,
+                )
+            };
+            sort_slice(row_data, self.single_threaded, |a, b| {
+                position(a).cmp(&position(b))
+            });
+        } else if self.sorting_enabled {
+            // Rows are collected from a map, so without a final tiebreaker equal rows would be
+            // shuffled on every reload
+            let sorted_by = &self.sorted_by;
+            let sort_order = self.sort_order;
+            let tiebreaker = self.tiebreaker.as_ref();
+            let compare =
+                |ordering: Ordering, a: &SelectableRow<Row, F>, b: &SelectableRow<Row, F>| {
+                    match sort_order {
+                        SortOrder::Ascending => ordering,
+                        SortOrder::Descending => ordering.reverse(),
+                    }
+                    .then_with(|| {
+                        tiebreaker.map_or(Ordering::Equal, |column| {
+                            column.order_by(&a.row_data, &b.row_data)
+                        })
+                    })
+                    .then_with(|| a.id.cmp(&b.id))
+                };
+
+            let has_sort_key = row_data
+                .first()
+                .is_some_and(|row| sorted_by.sort_key(&row.row_data).is_some());
+            if has_sort_key {
+                // Extract each key once instead of on every comparison, then sort the positions
+                // of the rows as the keys can borrow from them
+                let keys: Vec<_> = row_data
+                    .iter()
+                    .map(|row| sorted_by.sort_key(&row.row_data))
+                    .collect();
+                let mut order: Vec<usize> = (0..row_data.len()).collect();
+                sort_slice(&mut order, self.single_threaded, |a, b| {
+                    compare(keys[*a].cmp(&keys[*b]), &row_data[*a], &row_data[*b])
+                });
+                drop(keys);
+
+                let mut rows: Vec<Option<SelectableRow<Row, F>>> =
+                    std::mem::take(row_data).into_iter().map(Some).collect();
+                *row_data = order
+                    .into_iter()
+                    .filter_map(|index| rows[index].take())
+                    .collect();
+            } else {
+                sort_slice(row_data, self.single_threaded, |a, b| {
+                    compare(sorted_by.order_by(&a.row_data, &b.row_data), a, b)
+                });
+            }
+        } else {
+            // IDs are assigned incrementally so this is the order the rows were added in
+            sort_slice(row_data, self.single_threaded, |a, b| a.id.cmp(&b.id));
+        }
+    }
+
+    /// Clone the row for display, applying the view transform if one is set
+    fn view_row(
+        view_transform: Option<&ViewTransformFn<Row>>,
+        row: &SelectableRow<Row, F>,
+    ) -> SelectableRow<Row, F> {
+        view_transform.map_or_else(
+            || row.clone(),
+            |transform| SelectableRow {
+                row_data: transform(&row.row_data),
+                id: row.id,
+                selected_columns: row.selected_columns.clone(),
+            },
+        )
+    }
+
+    /// Whether the row contains the filter text of every filtered column
+    fn row_matches_filters(column_filters: &HashMap<F, String>, row: &Row) -> bool {
+        column_filters.iter().all(|(column, filter)| {
+            column
+                .search_text(row)
+                .to_lowercase()
+                .contains(filter.as_str())
+        })
+    }
+
+    /// Change the current sort order from ascending to descending and vice versa
+    fn change_sort_order(&mut self) {
         if matches!(self.sort_order, SortOrder::Ascending) {
             self.sort_order = SortOrder::Descending;
         } else {
             self.sort_order = SortOrder::Ascending;
         }
+        if self.remember_sort_direction {
+            self.sort_directions
+                .insert(self.sorted_by.clone(), self.sort_order);
+        }
+    }
+
+    /// Change the column that is currently being used for sorting
+    fn change_sorted_by(&mut self, sort_by: &F) {
+        if self.remember_sort_direction {
+            self.sort_directions
+                .insert(self.sorted_by.clone(), self.sort_order);
+        }
+        self.sorted_by = sort_by.clone();
+        self.sort_order = if self.remember_sort_direction {
+            self.sort_directions
+                .get(sort_by)
+                .copied()
+                .unwrap_or_default()
+        } else {
+            SortOrder::default()
+        };
+    }
+
+    /// Sets the columns that cannot be used for sorting.
+    ///
+    /// Clicking on the header of these columns will not change the sort column or order. The
+    /// header is still created and can respond to clicks for other purposes.
+    ///
+    /// # Parameters:
+    /// - `columns`: A `HashSet<F>` of the columns that should not be sortable.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_unsortable_columns(HashSet::from([Column::Actions]));
+    /// ```
+    pub fn set_unsortable_columns(&mut self, columns: HashSet<F>) {
+        self.unsortable_columns = columns;
+    }
+
+    /// Sets the columns that contain interactive widgets such as sliders or text edits.
+    ///
+    /// The cells of these columns do not get the forced drag sense, so the widgets inside them
+    /// receive drags normally. As a result, these cells cannot start or be part of a drag
+    /// selection. They are only selected by clicking if the `Response` returned by
+    /// `create_table_row` senses clicks, such as the response of a button, and otherwise
+    /// cannot be selected with the pointer.
+    ///
+    /// # Parameters:
+    /// - `columns`: A `HashSet<F>` of the columns with interactive cells.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_interactive_columns(HashSet::from([Column::Volume]));
+    /// ```
+    pub fn set_interactive_columns(&mut self, columns: HashSet<F>) {
+        self.interactive_columns = columns;
+    }
+
+    /// Sets a callback that is called whenever a column header is clicked.
+    ///
+    /// The callback receives the clicked column and is called before any sorting is done. Combine
+    /// with [`set_unsortable_columns`](#method.set_unsortable_columns) to use the header click for
+    /// something other than sorting, such as opening a filter popup.
+    ///
+    /// # Parameters:
+    /// - `callback`: A closure that takes a reference to the clicked column.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_on_header_click(|column| {
+    ///     println!("Header clicked");
+    /// });
+    /// ```
+    pub fn set_on_header_click<Fn>(&mut self, callback: Fn)
+    where
+        Fn: FnMut(&F) + Send + 'static,
+    {
+        self.on_header_click = Some(Box::new(callback));
+    }
+
+    /// Pins a row to the top of the table regardless of the current sort column and order.
+    ///
+    /// Pinned rows are displayed in the order they were pinned, before all other rows. Changes
+    /// are not immediately reflected in the UI. You must call [`recreate_rows`](#method.recreate_rows)
+    /// to apply these changes visually.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row to pin.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.pin_row(row_id);
+    /// table.recreate_rows();
+    /// ```
+    pub fn pin_row(&mut self, id: i64) {
+        if !self.pinned_rows.contains(&id) {
+            self.pinned_rows.push(id);
+        }
+    }
+
+    /// Unpins a row that was previously pinned with [`pin_row`](#method.pin_row). The row will
+    /// be sorted normally on the next [`recreate_rows`](#method.recreate_rows) call.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row to unpin.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.unpin_row(row_id);
+    /// table.recreate_rows();
+    /// ```
+    pub fn unpin_row(&mut self, id: i64) {
+        self.pinned_rows.retain(|pinned_id| *pinned_id != id);
+    }
+
+    /// Sets a callback that is called when the rows start and finish being sorted for display.
+    ///
+    /// Sorting happens whenever the rows are recreated. For very large tables this can cause a
+    /// visible hitch, and the callback can be used to show a "sorting" indicator for that frame.
+    ///
+    /// # Parameters:
+    /// - `callback`: A closure that receives the [`SortProgress`] stage.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_on_sort_progress(|progress| match progress {
+    ///     SortProgress::Started(total) => println!("Sorting {total} rows"),
+    ///     SortProgress::Finished(_) => println!("Sorting done"),
+    /// });
+    /// ```
+    pub fn set_on_sort_progress<Fn>(&mut self, callback: Fn)
+    where
+        Fn: FnMut(SortProgress) + Send + 'static,
+    {
+        self.on_sort_progress = Some(Box::new(callback));
+    }
+
+    /// Sets a callback that is called whenever a new row is added to the table.
+    ///
+    /// The callback receives the ID assigned to the row. Useful for keeping counters or external
+    /// copies of the rows in sync without polling [`total_rows`](#method.total_rows).
+    ///
+    /// # Parameters:
+    /// - `callback`: A closure that receives the ID of the added row.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_on_row_added(|id| println!("Row {id} added"));
+    /// ```
+    pub fn set_on_row_added<Fn>(&mut self, callback: Fn)
+    where
+        Fn: FnMut(i64) + Send + 'static,
+    {
+        self.on_row_added = Some(Box::new(callback));
+    }
+
+    /// Sets a callback that is called instead of [`select_all`](#method.select_all) when the
+    /// select all shortcut is pressed.
+    ///
+    /// Useful for changing what select all means, such as selecting the rows of the whole
+    /// dataset instead of only the displayed ones.
+    ///
+    /// # Parameters:
+    /// - `callback`: A closure that receives the table.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_on_select_all(|table| {
+    ///     table.select_index_range(0..100, None);
+    /// });
+    /// ```
+    pub fn set_on_select_all<Fn>(&mut self, callback: Fn)
+    where
+        Fn: FnMut(&mut Self) + Send + 'static,
+    {
+        self.on_select_all = Some(Box::new(callback));
+    }
+
+    /// Sets a callback for painting on top of the table, called each frame after the body is
+    /// drawn.
+    ///
+    /// The callback receives the UI the table was drawn in and the rect covering the header and
+    /// the visible part of the body. Useful for guides, markers or a loading veil over the cells.
+    ///
+    /// # Parameters:
+    /// - `callback`: A closure that receives the UI and the rect of the table.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_on_overlay(|ui, rect| {
+    ///     if loading {
+    ///         ui.painter().rect_filled(rect, 0.0, Color32::from_black_alpha(120));
+    ///     }
+    /// });
+    /// ```
+    pub fn set_on_overlay<Fn>(&mut self, callback: Fn)
+    where
+        Fn: FnMut(&mut Ui, Rect) + Send + 'static,
+    {
+        self.on_overlay = Some(Box::new(callback));
+    }
+
+    /// Removes the callback set with [`set_on_overlay`](#method.set_on_overlay).
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.clear_on_overlay();
+    /// ```
+    pub fn clear_on_overlay(&mut self) {
+        self.on_overlay = None;
+    }
+
+    /// Sets a function that creates the displayed version of each row.
+    ///
+    /// The function is applied whenever the rows are recreated, so the displayed rows contain the
+    /// transformed data while [`get_all_rows`](#method.get_all_rows) keeps the original rows.
+    /// Column filters are checked against the original rows and sorting uses the transformed
+    /// ones. Row IDs and selection are not affected.
+    ///
+    /// # Parameters:
+    /// - `transform`: A function that takes a stored row and returns the row to display.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_view_transform(|row: &MyRow| MyRow {
+    ///     name: row.name.to_uppercase(),
+    ///     ..row.clone()
+    /// });
+    /// table.recreate_rows();
+    /// ```
+    pub fn set_view_transform<Fn>(&mut self, transform: Fn)
+    where
+        Fn: std::ops::Fn(&Row) -> Row + Send + Sync + 'static,
+    {
+        self.view_transform = Some(Box::new(transform));
+    }
+
+    /// Removes the function set with [`set_view_transform`](#method.set_view_transform). You must
+    /// call [`recreate_rows`](#method.recreate_rows) to display the original rows.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.clear_view_transform();
+    /// table.recreate_rows();
+    /// ```
+    pub fn clear_view_transform(&mut self) {
+        self.view_transform = None;
+    }
+
+    /// Sets the filter text of a column. Only rows where the search text of the column contains
+    /// the filter text, ignoring case, are displayed. Filters of multiple columns are combined,
+    /// so a row must match all of them.
+    ///
+    /// An empty text removes the filter of the column. Changes are not immediately reflected in
+    /// the UI. You must call [`recreate_rows`](#method.recreate_rows) to apply the filters.
+    ///
+    /// # Parameters:
+    /// - `column`: The column to filter.
+    /// - `text`: The text the column must contain.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// // Inside create_header
+    /// if ui.text_edit_singleline(&mut filter_text).changed() {
+    ///     table.set_column_filter(ColumnName::Name, &filter_text);
+    ///     table.recreate_rows();
+    /// }
+    /// ```
+    pub fn set_column_filter(&mut self, column: F, text: &str) {
+        if text.is_empty() {
+            self.column_filters.remove(&column);
+        } else {
+            self.column_filters.insert(column, text.to_lowercase());
+        }
+    }
+
+    /// Sets whether selected rows that do not match the column filters should still be displayed.
+    ///
+    /// When enabled, selected rows filtered out by [`set_column_filter`](#method.set_column_filter)
+    /// are displayed at the top of the table, below the pinned rows, so the selection is not
+    /// lost while narrowing down the rows. Recreate the rows with
+    /// [`recreate_rows_no_unselect`](#method.recreate_rows_no_unselect) after changing a filter,
+    /// as [`recreate_rows`](#method.recreate_rows) clears the selection.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to keep the selected rows visible, `false` to filter them normally.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_keep_selected_visible(true);
+    /// table.set_column_filter(ColumnName::Name, "alice");
+    /// table.recreate_rows_no_unselect();
+    /// ```
+    pub const fn set_keep_selected_visible(&mut self, status: bool) {
+        self.keep_selected_visible = status;
+    }
+
+    /// Removes the filters of all columns. You must call [`recreate_rows`](#method.recreate_rows)
+    /// to display the unfiltered rows.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.clear_column_filters();
+    /// table.recreate_rows();
+    /// ```
+    pub fn clear_column_filters(&mut self) {
+        self.column_filters.clear();
+    }
+
+    /// Recreates the rows shown in the UI for the next frame load.
+    ///
+    /// # Performance:
+    /// - Should be used sparingly for large datasets as frequent calls can lead to performance issues.
+    /// - Consider calling after every X amount row updates, based on how frequently new rows are being
+    ///   added or use [`auto_scroll`](#method.auto_scroll) for automatic reload.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.recreate_rows();
+    /// ```
+    pub fn recreate_rows(&mut self) {
+        self.formatted_rows.clear();
+        self.active_rows.clear();
+        self.active_columns.clear();
+        self.hidden_selection.clear();
+        self.sort_rows();
+    }
+
+    /// Recreates the rows shown in the UI for the next frame load without clearing the current
+    /// selection.
+    ///
+    /// Selected rows that still exist after recreation keep their selection. Rows that were
+    /// removed are dropped from the selection.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.recreate_rows_no_unselect();
+    /// ```
+    pub fn recreate_rows_no_unselect(&mut self) {
+        // Each selected row can have a different set of selected columns. Save them before the
+        // displayed rows are recreated
+        let mut selected_cells = HashMap::new();
+        for id in &self.active_rows {
+            if let Some(index) = self.indexed_ids.get(id) {
+                let selected_columns =
+                    std::mem::take(&mut self.formatted_rows[*index].selected_columns);
+                selected_cells.insert(*id, selected_columns);
+            }
+        }
+        selected_cells.extend(self.hidden_selection.drain());
+
+        self.formatted_rows.clear();
+        self.active_columns.clear();
+        // The selected rows are still needed while sorting to keep them visible
+        self.sort_rows();
+        self.active_rows.clear();
+
+        for (id, selected_columns) in selected_cells {
+            let Some(index) = self.indexed_ids.get(&id) else {
+                if self.hidden_rows.contains(&id) && self.rows.contains_key(&id) {
+                    self.hidden_selection.insert(id, selected_columns);
+                }
+                continue;
+            };
+            self.active_columns.extend(selected_columns.iter().cloned());
+            self.formatted_rows[*index].selected_columns = selected_columns;
+            self.active_rows.insert(id);
+        }
+    }
+
+    /// The first column that was passed by the user. `None` if the table has no columns
+    fn first_column(&self) -> Option<F> {
+        self.all_columns.first().cloned()
+    }
+
+    /// The last column that was passed by the user. `None` if the table has no columns
+    fn last_column(&self) -> Option<F> {
+        self.all_columns.last().cloned()
+    }
+
+    /// Convert a number to a column value
+    fn column_to_num(&self, column: &F) -> usize {
+        *self
+            .column_number
+            .get(column)
+            .expect("Not in the column list")
+    }
+
+    /// Returns the column at the given position, counting from 0. The serial column is not
+    /// counted.
+    ///
+    /// This is the inverse of the position of a column in the list passed to
+    /// [`new`](#method.new), useful for features working with positional column indices.
+    ///
+    /// # Parameters:
+    /// - `index`: The position of the column.
+    ///
+    /// # Returns:
+    /// - `Option<F>`: The column at the position, or `None` if the index is out of bounds.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let first_column = table.column_at(0);
+    /// ```
+    pub fn column_at(&self, index: usize) -> Option<F> {
+        self.all_columns.get(index).cloned()
+    }
+
+    /// Sets which columns are displayed and in what order in a single call.
+    ///
+    /// Columns that were part of the table but are not in `ordered_visible` are hidden and can
+    /// be shown again by including them in a later call. The selection of hidden columns is
+    /// kept, but they are not copied while hidden. The `show_ui` builder must add a column for
+    /// each visible column, see [`expected_column_count`](#method.expected_column_count).
+    ///
+    /// # Parameters:
+    /// - `ordered_visible`: The columns to display, in display order. Must contain at least one
+    ///   column, which is asserted in debug builds.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// // Applied from a column picker dialog
+    /// table.set_columns_layout(vec![ColumnName::Email, ColumnName::Name]);
+    /// ```
+    pub fn set_columns_layout(&mut self, ordered_visible: Vec<F>) {
+        debug_assert!(
+            !ordered_visible.is_empty(),
+            "SelectableTable requires at least one column"
+        );
+
+        let visible: HashSet<&F> = ordered_visible.iter().collect();
+        let mut hidden_columns: HashSet<F> = self
+            .all_columns
+            .iter()
+            .filter(|column| !visible.contains(column))
+            .cloned()
+            .collect();
+        hidden_columns.extend(
+            self.hidden_columns
+                .iter()
+                .filter(|column| !visible.contains(column))
+                .cloned(),
+        );
+
+        self.column_number = ordered_visible
+            .iter()
+            .enumerate()
+            .map(|(index, column)| (column.clone(), index))
+            .collect();
+        self.hidden_columns = hidden_columns;
+        self.all_columns = ordered_visible;
+    }
+
+    /// Returns whether the column was hidden with
+    /// [`set_columns_layout`](#method.set_columns_layout).
+    ///
+    /// # Parameters:
+    /// - `column`: The column to check.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let hidden = table.is_column_hidden(&ColumnName::Email);
+    /// ```
+    pub fn is_column_hidden(&self, column: &F) -> bool {
+        self.hidden_columns.contains(column)
+    }
+
+    /// Measures the widest text of the column among the displayed rows.
+    ///
+    /// The text of each row is measured with the body font of the UI. Only the first
+    /// [`MEASURE_ROW_LIMIT`] displayed rows are measured to keep this cheap on large tables. The
+    /// result can be used with `Column::initial` to fit the column to its content.
+    ///
+    /// # Parameters:
+    /// - `ui`: The UI used to get the font and spacing.
+    /// - `column`: The column to measure.
+    ///
+    /// # Returns:
+    /// - `f32`: The width of the widest text including the spacing around the cell.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let width = table.measure_column(ui, &ColumnName::Name);
+    /// table.show_ui(ui, |builder| builder.column(Column::initial(width)));
+    /// ```
+    pub fn measure_column(&self, ui: &Ui, column: &F) -> f32 {
+        let font_id = TextStyle::Body.resolve(ui.style());
+        let color = ui.visuals().text_color();
+
+        let max_width = ui.fonts(|fonts| {
+            self.formatted_rows
+                .iter()
+                .take(MEASURE_ROW_LIMIT)
+                .map(|row| {
+                    let text = column.column_text(&row.row_data);
+                    fonts.layout_no_wrap(text, font_id.clone(), color).size().x
+                })
+                .fold(0.0, f32::max)
+        });
+        ui.spacing().item_spacing.x.mul_add(2.0, max_width)
+    }
+
+    /// Get the next column of the provided column
+    fn next_column(&self, column: &F) -> F {
+        let current_column_num = self.column_to_num(column);
+        if current_column_num == self.all_columns.len() - 1 {
+            self.all_columns[0].clone()
+        } else {
+            self.all_columns[current_column_num + 1].clone()
+        }
+    }
+
+    /// Get the previous column of the provided column
+    fn previous_column(&self, column: &F) -> F {
+        let current_column_num = self.column_to_num(column);
+        if current_column_num == 0 {
+            self.all_columns[self.all_columns.len() - 1].clone()
+        } else {
+            self.all_columns[current_column_num - 1].clone()
+        }
+    }
+
+    /// Changes the selection after a click on a cell based on the held modifiers
+    fn handle_cell_click(
+        &mut self,
+        ui: &Ui,
+        id: i64,
+        column_name: &F,
+        selected: bool,
+        is_ctrl_pressed: bool,
+        is_subtract_pressed: bool,
+    ) {
+        // SHIFT extends the selection from the anchor to the clicked cell
+        let is_shift_pressed =
+            ui.input(|i| i.modifiers.shift) && self.selection_mode == SelectionMode::Multi;
+
+        if is_subtract_pressed {
+            self.unselect_single_row_cell(id, column_name);
+        } else if is_ctrl_pressed && !is_shift_pressed && selected {
+            // Adding an already selected cell toggles it off
+            self.unselect_single_row_cell(id, column_name);
+            self.selection_anchor = Some((id, column_name.clone()));
+        } else {
+            // If the selection is not being added to and the mouse right click is not
+            // pressed, unselect all cells
+            if !is_ctrl_pressed && !ui.input(|i| i.pointer.secondary_clicked()) {
+                self.unselect_all();
+            }
+
+            if is_shift_pressed && self.selection_anchor.is_some() {
+                self.select_anchor_range(id, column_name);
+            } else {
+                self.select_single_row_cell(id, column_name);
+                self.selection_anchor = Some((id, column_name.clone()));
+            }
+        }
+    }
+
+    /// Paints the background of a cell before its content and records where the cell is
+    fn prepare_cell(&mut self, ui: &Ui, row_data: &SelectableRow<Row, F>, column_name: &F) {
+        if let Some(color) = self.dirty_row_tint {
+            if self.dirty_rows.contains(&row_data.id) {
+                ui.painter().rect_filled(ui.max_rect(), 0.0, color);
+            }
+        }
+        if let Some(color) = self.row_flash_color(row_data.id) {
+            ui.painter().rect_filled(ui.max_rect(), 0.0, color);
+        }
+
+        self.cell_rects
+            .insert((row_data.id, column_name.clone()), ui.max_rect());
+        if ui.ui_contains_pointer() {
+            self.hovered_cell = Some((row_data.id, column_name.clone()));
+        }
+
+        if self.uniform_selection_style && row_data.selected_columns.contains(column_name) {
+            let selection_color = ui.visuals().selection.bg_fill;
+            ui.painter()
+                .rect_filled(ui.max_rect(), 0.0, selection_color);
+        }
+    }
+
+    /// Builds the table's Body section
+    fn handle_table_body(&mut self, mut row: TableRow, row_data: &SelectableRow<Row, F>) {
+        for column_name in &self.all_columns.clone() {
+            row.col(|ui| {
+                self.prepare_cell(ui, row_data, column_name);
+
+                let selected = row_data.selected_columns.contains(column_name);
+                let mut resp = column_name.create_table_row(ui, row_data, selected, self);
+
+                // Drag sense is forced otherwise there is no point of this library. Interactive
+                // columns are left alone so the widgets inside them can use the drag.
+                let is_interactive = self.interactive_columns.contains(column_name);
+                if !is_interactive {
+                    resp = resp.interact(Sense::drag());
+                }
+                if self.row_resize.enabled {
+                    self.handle_row_resize(ui, row_data.id);
+                }
+
+                // A right drag adds to the existing selection the same way a CTRL drag does
+                let is_additive_drag =
+                    self.right_drag_adds_selection && ui.input(|i| i.pointer.secondary_down());
+
+                // Modifiers only change the existing selection when multiple selections are
+                // allowed
+                let action = self.selection_behavior.action(ui.input(|i| i.modifiers));
+                let is_multi = self.selection_mode == SelectionMode::Multi;
+                let is_subtract_pressed =
+                    action == SelectionAction::Subtract && is_multi && !is_additive_drag;
+                let is_ctrl_pressed =
+                    (action == SelectionAction::Add || is_additive_drag) && is_multi;
+
+                let time = ui.input(|i| i.time);
+                if ui.input(|i| i.pointer.primary_pressed()) {
+                    self.press_started_at = Some(time);
+                }
+                // On touchscreens a tap often moves slightly and registers as a drag. Until the
+                // pointer is held down for the drag delay, the drag is not used for selection
+                let held_long_enough = self
+                    .press_started_at
+                    .is_none_or(|started_at| time - started_at >= self.drag_delay.as_secs_f64());
+
+                // Dragging from a cell of a multi-selection leaves the selection as is so the
+                // group can be dragged elsewhere. A click without a drag still clears it.
+                let preserve_selection = self.click_preserves_multiselect
+                    && !is_subtract_pressed
+                    && selected
+                    && (self.active_rows.len() > 1 || row_data.selected_columns.len() > 1);
+
+                if resp.dragged()
+                    && self.drag_started_on.is_none()
+                    && held_long_enough
+                    && !is_interactive
+                    && !preserve_selection
+                    && !self.pointer_over_scrollbar
+                {
+                    // If the selection is not being added to or subtracted from and the mouse
+                    // right click is not pressed, unselect all cells. Right click for context menu
+                    if !is_ctrl_pressed
+                        && !is_subtract_pressed
+                        && !ui.ctx().input(|i| i.pointer.secondary_clicked())
+                    {
+                        self.unselect_all();
+                    }
+                    if is_subtract_pressed {
+                        self.subtract_drag_base = Some(self.get_selection());
+                    }
+                    self.drag_started_on = Some((row_data.id, column_name.clone()));
+                }
+
+                self.track_drag_rect(ui, row_data.id, column_name);
+
+                let pointer_released = ui.input(|a| a.pointer.any_released());
+
+                if pointer_released {
+                    self.last_active_row = None;
+                    self.last_active_column = None;
+                    self.drag_started_on = None;
+                    self.subtract_drag_base = None;
+                    self.beyond_drag_point = false;
+                    self.edge_hover_since = None;
+                }
+
+                // A drag released before the drag delay is treated as a tap
+                let delayed_tap = resp.drag_stopped() && !held_long_enough && !is_interactive;
+
+                if resp.clicked() || delayed_tap {
+                    self.handle_cell_click(
+                        ui,
+                        row_data.id,
+                        column_name,
+                        selected,
+                        is_ctrl_pressed,
+                        is_subtract_pressed,
+                    );
+                }
+
+                if !is_interactive
+                    && !self.pointer_over_scrollbar
+                    && ui.ui_contains_pointer()
+                    && self.drag_started_on.is_some()
+                {
+                    if let Some(drag_start) = self.drag_started_on.as_ref() {
+                        // Only call drag either when not on the starting drag row/column or went beyond the
+                        // drag point at least once. Otherwise normal click would be considered as drag
+                        if drag_start.0 != row_data.id
+                            || &drag_start.1 != column_name
+                            || self.beyond_drag_point
+                        {
+                            if self.subtract_drag_base.is_some() {
+                                self.subtract_dragged_row_cell(row_data.id, column_name);
+                            } else {
+                                self.select_dragged_row_cell(
+                                    row_data.id,
+                                    column_name,
+                                    is_ctrl_pressed,
+                                );
+                            }
+                        }
+                    }
+                }
+            });
+        }
+    }
+
+    /// Returns the number of columns the table renders, including the serial and selection
+    /// columns if enabled.
+    ///
+    /// Useful for asserting that the `TableBuilder` closure passed to
+    /// [`show_ui`](#method.show_ui) adds the right amount of columns. The closure should add
+    /// one column for each of the table columns, while the serial and selection columns are
+    /// added internally.
+    ///
+    /// # Returns:
+    /// - `usize`: The total number of columns in the rendered table.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3]).serial_column();
+    /// assert_eq!(table.expected_column_count(), 4);
+    /// ```
+    pub fn expected_column_count(&self) -> usize {
+        self.all_columns.len()
+            + usize::from(self.add_serial_column)
+            + usize::from(self.add_selection_column)
+    }
+
+    /// Checks that the displayed rows and the selection are consistent with each other.
+    ///
+    /// Meant for debugging. Editing the displayed rows with
+    /// [`modify_shown_row`](#method.modify_shown_row) can break the table in ways that only show
+    /// up later as selection bugs, such as the same row being displayed twice.
+    ///
+    /// # Errors
+    /// Returns a description of the first inconsistency found:
+    /// - A row ID is displayed more than once.
+    /// - The index of a displayed row does not match its position.
+    /// - A selected row does not exist in the table.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.modify_shown_row(|rows, indexed_ids| { /* modify rows */ });
+    /// debug_assert_eq!(table.validate_invariants(), Ok(()));
+    /// ```
+    pub fn validate_invariants(&self) -> Result<(), String> {
+        let mut seen_ids = HashSet::new();
+        for (index, row) in self.formatted_rows.iter().enumerate() {
+            if !seen_ids.insert(row.id) {
+                return Err(format!("Row ID {} is displayed more than once", row.id));
+            }
+            match self.indexed_ids.get(&row.id) {
+                Some(indexed) if *indexed == index => {}
+                Some(indexed) => {
+                    return Err(format!(
+                        "Row ID {} is displayed at {index} but indexed at {indexed}",
+                        row.id
+                    ));
+                }
+                None => return Err(format!("Row ID {} is displayed but not indexed", row.id)),
+            }
+        }
+
+        if self.indexed_ids.len() != self.formatted_rows.len() {
+            return Err(format!(
+                "{} rows are indexed but {} rows are displayed",
+                self.indexed_ids.len(),
+                self.formatted_rows.len()
+            ));
+        }
+
+        if let Some(id) = self
+            .active_rows
+            .iter()
+            .find(|id| !self.rows.contains_key(id))
+        {
+            return Err(format!("Selected row ID {id} does not exist"));
+        }
+        Ok(())
+    }
+
+    /// Returns the total number of rows currently being displayed in the UI.
+    ///
+    /// # Returns:
+    /// - `usize`: The number of rows that are formatted and ready for display.
+    pub const fn total_displayed_rows(&self) -> usize {
+        self.formatted_rows.len()
+    }
+
+    /// Returns the total number of rows in the table (both displayed and non-displayed).
+    ///
+    /// # Returns:
+    /// - `usize`: The total number of rows stored in the table, regardless of whether they are being displayed or not.
+    pub fn total_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the range of display indices of the rows that are currently visible on screen.
+    ///
+    /// The range is calculated from the scroll offset and the size of the table body as of the
+    /// last frame. Can be used to load heavy per-row resources only for the visible rows.
+    ///
+    /// # Returns:
+    /// - `Range<usize>`: The indices of `formatted_rows` that are visible. Empty before the
+    ///   table is shown for the first time.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// for index in table.visible_row_range() {
+    ///     let row = &table.get_displayed_rows()[index];
+    /// }
+    /// ```
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn visible_row_range(&self) -> Range<usize> {
+        let scroll_offset = self.auto_scroll.scroll_offset.max(0.0);
+        let view_end = scroll_offset + self.view_height;
+
+        if self.row_resize.heights.is_empty() && self.expanded_rows.is_empty() {
+            let row_step = self.row_height + self.row_spacing;
+            let start =
+                ((scroll_offset / row_step).floor() as usize).min(self.formatted_rows.len());
+            let end = ((view_end / row_step).ceil() as usize).min(self.formatted_rows.len());
+            return start..end;
+        }
+
+        // Resized rows and detail rows have their own height so walk down the body the same
+        // way it is drawn to find the visible rows. A visible detail row counts as its row
+        let mut start = self.formatted_rows.len();
+        let mut end = 0;
+        let mut top = 0.0;
+        for body_row in self.body_layout() {
+            if top >= view_end {
+                break;
+            }
+            let bottom = top + body_row.height + self.row_spacing;
+            if bottom > scroll_offset {
+                start = start.min(body_row.index);
+                end = body_row.index + 1;
+            }
+            top = bottom;
+        }
+
+        start.min(end)..end
+    }
+
+    /// Scroll to the selected row on the next frame if a single row is selected and it is not in
+    /// view. Returns whether a scroll was requested
+    fn scroll_to_selected_if_hidden(&mut self) -> bool {
+        if self.active_rows.len() != 1 {
+            return false;
+        }
+        let Some(index) = self
+            .active_rows
+            .iter()
+            .next()
+            .and_then(|id| self.indexed_ids.get(id))
+            .copied()
+        else {
+            return false;
+        };
+        if self.visible_row_range().contains(&index) {
+            return false;
+        }
+        self.scroll_to_index = Some(index);
+        true
+    }
+
+    /// Returns the screen rect of a cell rendered in the last frame.
+    ///
+    /// Useful for placing popups or editors over a specific cell. Only the visible rows are
+    /// rendered, so cells that are scrolled out of view return `None`.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row of the cell.
+    /// - `column`: The column of the cell.
+    ///
+    /// # Returns:
+    /// - `Option<Rect>`: The rect of the cell, or `None` if it was not rendered.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// if let Some(rect) = table.cell_rect(row_id, &ColumnName::Name) {
+    ///     egui::Area::new("editor".into()).fixed_pos(rect.left_bottom()).show(ctx, |ui| {
+    ///         ui.label("Suggestions");
+    ///     });
+    /// }
+    /// ```
+    pub fn cell_rect(&self, id: i64, column: &F) -> Option<Rect> {
+        self.cell_rects.get(&(id, column.clone())).copied()
+    }
+
+    /// Returns the cell under the pointer in the last frame.
+    ///
+    /// Independent of the selection, so it can drive hover based UI such as a preview of the
+    /// hovered row in a side panel.
+    ///
+    /// # Returns:
+    /// - `Option<(i64, F)>`: The row ID and column of the hovered cell, or `None` if the pointer
+    ///   was not over any cell.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// if let Some((id, _)) = table.hovered_cell() {
+    ///     show_preview(ui, id);
+    /// }
+    /// ```
+    pub fn hovered_cell(&self) -> Option<(i64, F)> {
+        self.hovered_cell.clone()
+    }
+
+    /// Returns the scroll offset of the table as of the last frame.
+    ///
+    /// # Returns:
+    /// - `Vec2`: The horizontal offset of the horizontal scrollbar, if enabled, and the vertical
+    ///   offset of the table body.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let is_at_top = table.scroll_offset().y == 0.0;
+    /// ```
+    pub const fn scroll_offset(&self) -> Vec2 {
+        self.scroll_offset
+    }
+
+    /// Returns whether the last frame was rendered inside the horizontal scroll area enabled
+    /// with [`horizontal_scroll`](#method.horizontal_scroll).
+    ///
+    /// # Returns:
+    /// - `bool`: `true` if the last frame had a horizontal scrollbar, `false` if it did not or
+    ///   the table was not shown yet.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// if table.rendered_horizontal() {
+    ///     ui.label(format!("Scrolled {} px to the right", table.scroll_offset().x));
+    /// }
+    /// ```
+    pub const fn rendered_horizontal(&self) -> bool {
+        self.rendered_horizontal
+    }
+
+    /// Provides a reference to the rows currently being displayed in the UI.
+    ///
+    /// # Returns:
+    /// - `&Vec<SelectableRow<Row, F>>`: A reference to the vector of formatted rows ready for display.
+    pub const fn get_displayed_rows(&self) -> &Vec<SelectableRow<Row, F>> {
+        &self.formatted_rows
+    }
+
+    /// Provides a reference to all rows in the table, regardless of whether they are displayed.
+    ///
+    /// # Returns:
+    /// - `&HashMap<i64, SelectableRow<Row, F>>`: A reference to the entire collection of rows in the table.
+    pub const fn get_all_rows(&self) -> &HashMap<i64, SelectableRow<Row, F>> {
+        &self.rows
+    }
+
+    /// Groups the IDs of all rows by a key computed from each row.
+    ///
+    /// This only queries the stored rows and does not change what is displayed. The IDs in each
+    /// group are in the order the rows were added.
+    ///
+    /// # Parameters:
+    /// - `key`: A function that returns the group key of a row.
+    ///
+    /// # Returns:
+    /// - `HashMap<K, Vec<i64>>`: The row IDs of each group.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let by_status = table.group_by(|row| row.status.clone());
+    /// let failed_count = by_status.get(&Status::Failed).map_or(0, Vec::len);
+    /// ```
+    pub fn group_by<K, Fn>(&self, key: Fn) -> HashMap<K, Vec<i64>>
+    where
+        K: Eq + Hash,
+        Fn: std::ops::Fn(&Row) -> K,
+    {
+        let mut groups: HashMap<K, Vec<i64>> = HashMap::new();
+        for (id, row) in &self.rows {
+            groups.entry(key(&row.row_data)).or_default().push(*id);
+        }
+        for ids in groups.values_mut() {
+            ids.sort_unstable();
+        }
+        groups
+    }
+
+    /// Adds a serial column to the table.
+    ///
+    /// The serial column is automatically generated and displayed at the very left of the table.
+    /// It shows the row number (starting from 1) for each row.
+    ///
+    /// # Returns:
+    /// - `Self`: The modified table with the serial column enabled.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .config(my_config).serial_column();
+    /// ```
+    #[must_use]
+    pub const fn serial_column(mut self) -> Self {
+        self.add_serial_column = true;
+        self
+    }
+
+    /// Makes the row numbers of the serial column count down when sorted in descending order.
+    ///
+    /// Useful for ranked tables where the number should reflect the rank of the row regardless
+    /// of the sort direction. With 10 rows sorted descending, the first row is numbered 10.
+    ///
+    /// # Returns:
+    /// - `Self`: The modified table with reversed row numbers.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .serial_column()
+    ///     .serial_reverse();
+    /// ```
+    #[must_use]
+    pub const fn serial_reverse(mut self) -> Self {
+        self.serial_reverse = true;
+        self
+    }
+
+    /// Sets whether the row numbers of the serial column should count down when sorted in
+    /// descending order.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to count down on a descending sort, `false` to always count up.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_serial_reverse(true);
+    /// ```
+    pub const fn set_serial_reverse(&mut self, status: bool) {
+        self.serial_reverse = status;
+    }
+
+    /// Sets a callback that draws the content of the serial column instead of the row number.
+    ///
+    /// Useful for turning the serial column into a gutter with a status indicator or a drag
+    /// handle. The row number passed to the callback follows
+    /// [`serial_reverse`](#method.serial_reverse).
+    ///
+    /// # Parameters:
+    /// - `callback`: A closure that receives the UI of the cell, the row number and the row.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_serial_content(|ui, serial, row| {
+    ///     let color = if row.row_data.failed { Color32::RED } else { Color32::GREEN };
+    ///     ui.colored_label(color, format!("● {serial}"));
+    /// });
+    /// ```
+    pub fn set_serial_content<Fn>(&mut self, callback: Fn)
+    where
+        Fn: FnMut(&mut Ui, usize, &SelectableRow<Row, F>) + Send + 'static,
+    {
+        self.serial_content = Some(Box::new(callback));
+    }
+
+    /// Removes the callback set with [`set_serial_content`](#method.set_serial_content) to show
+    /// the row number again.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.clear_serial_content();
+    /// ```
+    pub fn clear_serial_content(&mut self) {
+        self.serial_content = None;
+    }
+
+    /// Paints a semi-transparent rectangle over the cells being selected during a drag.
+    ///
+    /// The rectangle spans from the cell where the drag started to the cell currently under the
+    /// pointer and uses the selection color of the current theme.
+    ///
+    /// # Returns:
+    /// - `Self`: The modified table with the selection rectangle enabled.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .draw_selection_rect();
+    /// ```
+    #[must_use]
+    pub const fn draw_selection_rect(mut self) -> Self {
+        self.draw_selection_rect = true;
+        self
+    }
+
+    /// Sets whether a rectangle should be painted over the cells being selected during a drag.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to paint the selection rectangle, `false` to disable it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_draw_selection_rect(true);
+    /// ```
+    pub const fn set_draw_selection_rect(&mut self, status: bool) {
+        self.draw_selection_rect = status;
+    }
+
+    /// Paints the selection background behind the selected cells.
+    ///
+    /// The background is painted before `create_table_row` is called, so selection looks the
+    /// same regardless of the widget used for the cell. Useful when the cells are plain labels
+    /// that do not show the selection themselves.
+    ///
+    /// # Returns:
+    /// An updated instance of the table with the selection background enabled.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .uniform_selection_style();
+    /// ```
+    #[must_use]
+    pub const fn uniform_selection_style(mut self) -> Self {
+        self.uniform_selection_style = true;
+        self
+    }
+
+    /// Sets whether the selection background should be painted behind the selected cells.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to paint the selection background, `false` to disable it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_uniform_selection_style(true);
+    /// ```
+    pub const fn set_uniform_selection_style(&mut self, status: bool) {
+        self.uniform_selection_style = status;
+    }
+
+    /// Paints a shadow below the header while the body is scrolled, hinting that there are
+    /// rows above the visible ones.
+    ///
+    /// # Returns:
+    /// An updated instance of the table with the header shadow enabled.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .header_shadow();
+    /// ```
+    #[must_use]
+    pub const fn header_shadow(mut self) -> Self {
+        self.header_shadow = true;
+        self
+    }
+
+    /// Sets whether a shadow should be painted below the header while the body is scrolled.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to paint the shadow, `false` to disable it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_header_shadow(true);
+    /// ```
+    pub const fn set_header_shadow(&mut self, status: bool) {
+        self.header_shadow = status;
+    }
+
+    /// Sets the height of each row in the table body. Default: 25.0
+    ///
+    /// # Parameters:
+    /// - `height`: The row height in pixels.
+    ///
+    /// # Returns:
+    /// - `Self`: The modified table with the new row height.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .row_height(30.0);
+    /// ```
+    #[must_use]
+    pub const fn row_height(mut self, height: f32) -> Self {
+        self.row_height = height;
+        self
+    }
+
+    /// Sets the height of each row in the table body at runtime.
+    ///
+    /// # Parameters:
+    /// - `height`: The row height in pixels.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_row_height(30.0);
+    /// ```
+    pub const fn set_row_height(&mut self, height: f32) {
+        self.row_height = height;
+    }
+
+    /// Sets the height of the header row. Default: 20.0
+    ///
+    /// # Parameters:
+    /// - `height`: The header height in pixels.
+    ///
+    /// # Returns:
+    /// - `Self`: The modified table with the new header height.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .header_height(24.0);
+    /// ```
+    #[must_use]
+    pub const fn header_height(mut self, height: f32) -> Self {
+        self.header_height = height;
+        self
+    }
+
+    /// Sets the height of the header row at runtime.
+    ///
+    /// # Parameters:
+    /// - `height`: The header height in pixels.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_header_height(24.0);
+    /// ```
+    pub const fn set_header_height(&mut self, height: f32) {
+        self.header_height = height;
+    }
+
+    /// Applies a [`Density`] preset for the row height, header height and cell spacing.
+    ///
+    /// The preset can be further adjusted by calling [`row_height`](#method.row_height) or
+    /// [`header_height`](#method.header_height) afterward.
+    ///
+    /// # Parameters:
+    /// - `density`: The `Density` preset to apply.
+    ///
+    /// # Returns:
+    /// - `Self`: The modified table with the preset applied.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .density(Density::Compact);
+    /// ```
+    #[must_use]
+    pub const fn density(mut self, density: Density) -> Self {
+        self.set_density(density);
+        self
     }
 
-    /// Change the column that is currently being used for sorting. Will unselect all rows
-    fn change_sorted_by(&mut self, sort_by: &F) {
-        self.unselect_all();
-        self.sorted_by = sort_by.clone();
-        self.sort_order = SortOrder::default();
+    /// Applies a [`Density`] preset for the row height, header height and cell spacing at
+    /// runtime.
+    ///
+    /// # Parameters:
+    /// - `density`: The `Density` preset to apply.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_density(Density::Comfortable);
+    /// ```
+    pub const fn set_density(&mut self, density: Density) {
+        let (row_height, header_height, cell_spacing) = density.values();
+        self.row_height = row_height;
+        self.header_height = header_height;
+        self.cell_spacing = cell_spacing;
     }
 
-    /// Recreates the rows shown in the UI for the next frame load.
+    /// Scrolls back to the selected row when the table is resized and the row is no longer in
+    /// view.
     ///
-    /// # Performance:
-    /// - Should be used sparingly for large datasets as frequent calls can lead to performance issues.
-    /// - Consider calling after every X amount row updates, based on how frequently new rows are being
-    ///     added or use [`auto_scroll`](#method.auto_scroll) for automatic reload.
+    /// Only applies when a single row is selected. Keeps the focus of the user stable when the
+    /// window or the surrounding layout changes size.
+    ///
+    /// # Returns:
+    /// - `Self`: The modified table that keeps the selection visible on resize.
     ///
     /// # Example:
     /// ```rust,ignore
-    /// table.recreate_rows();
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .keep_selection_visible_on_resize();
     /// ```
-    pub fn recreate_rows(&mut self) {
-        self.formatted_rows.clear();
-        self.active_rows.clear();
-        self.active_columns.clear();
-        self.sort_rows();
+    #[must_use]
+    pub const fn keep_selection_visible_on_resize(mut self) -> Self {
+        self.keep_selection_visible_on_resize = true;
+        self
     }
 
-    /// The first column that was passed by the user
-    fn first_column(&self) -> F {
-        self.all_columns[0].clone()
+    /// Sets whether the table should scroll back to the selected row when it is resized.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to keep the selected row visible on resize, `false` to disable it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_keep_selection_visible_on_resize(true);
+    /// ```
+    pub const fn set_keep_selection_visible_on_resize(&mut self, status: bool) {
+        self.keep_selection_visible_on_resize = status;
+        self.last_table_size = None;
     }
 
-    /// The last column that was passed by the user
-    fn last_column(&self) -> F {
-        self.all_columns[self.all_columns.len() - 1].clone()
+    /// Limits the height of the table body. Rows beyond the height are scrolled within the
+    /// table regardless of the space given by the parent UI.
+    ///
+    /// Useful when embedding the table in a dialog or a panel that should not grow with the
+    /// number of rows. Calling `max_scroll_height` inside the `show_ui` builder overrides this.
+    ///
+    /// # Parameters:
+    /// - `height`: The maximum height of the table body, excluding the header.
+    ///
+    /// # Returns:
+    /// - `Self`: The modified table with the maximum height set.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .max_height(300.0);
+    /// ```
+    #[must_use]
+    pub const fn max_height(mut self, height: f32) -> Self {
+        self.max_height = Some(height);
+        self
     }
 
-    /// Convert a number to a column value
-    fn column_to_num(&self, column: &F) -> usize {
-        *self
-            .column_number
-            .get(column)
-            .expect("Not in the column list")
+    /// Sets the maximum height of the table body.
+    ///
+    /// # Parameters:
+    /// - `height`: The maximum height of the table body, or `None` to use the space given by
+    ///   the parent UI.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_max_height(Some(300.0));
+    /// ```
+    pub const fn set_max_height(&mut self, height: Option<f32>) {
+        self.max_height = height;
     }
 
-    /// Get the next column of the provided column
-    fn next_column(&self, column: &F) -> F {
-        let current_column_num = self.column_to_num(column);
-        if current_column_num == self.all_columns.len() - 1 {
-            self.all_columns[0].clone()
-        } else {
-            self.all_columns[current_column_num + 1].clone()
-        }
+    /// Changes the sort only when a header is double clicked instead of on every click.
+    ///
+    /// Prevents accidental re-sorts, which are expensive on large tables. Single clicks still
+    /// call the callback set with [`set_on_header_click`](#method.set_on_header_click).
+    ///
+    /// # Returns:
+    /// - `Self`: The modified table with double click sorting enabled.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .sort_on_double_click();
+    /// ```
+    #[must_use]
+    pub const fn sort_on_double_click(mut self) -> Self {
+        self.sort_on_double_click = true;
+        self
     }
 
-    /// Get the previous column of the provided column
-    fn previous_column(&self, column: &F) -> F {
-        let current_column_num = self.column_to_num(column);
-        if current_column_num == 0 {
-            self.all_columns[self.all_columns.len() - 1].clone()
-        } else {
-            self.all_columns[current_column_num - 1].clone()
-        }
+    /// Sets whether the sort should change on a double click on a header instead of a single
+    /// click.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to sort on double click, `false` to sort on single click.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_sort_on_double_click(true);
+    /// ```
+    pub const fn set_sort_on_double_click(&mut self, status: bool) {
+        self.sort_on_double_click = status;
     }
 
-    /// Builds the table's Body section
-    fn handle_table_body(&mut self, mut row: TableRow, row_data: &SelectableRow<Row, F>) {
-        for column_name in &self.all_columns.clone() {
-            row.col(|ui| {
-                let selected = row_data.selected_columns.contains(column_name);
-                let mut resp = column_name.create_table_row(ui, row_data, selected, self);
-
-                // Drag sense is forced otherwise there is no point of this library.
-                resp = resp.interact(Sense::drag());
+    /// Sets whether clicking on a header should only change the sort column and order without
+    /// recreating the rows.
+    ///
+    /// By default, a header click re-sorts the rows right away and clears the selection. When
+    /// deferred, the new sort is applied the next time the rows are recreated, so the app can
+    /// decide when to pay for the re-sort and whether to keep the selection with
+    /// [`recreate_rows_no_unselect`](#method.recreate_rows_no_unselect). Check
+    /// [`is_sort_pending`](#method.is_sort_pending) to know when a header was clicked.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to defer the re-sort, `false` to re-sort on every header click.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_defer_header_sort(true);
+    /// table.show_ui(ui, |builder| builder);
+    /// if table.is_sort_pending() {
+    ///     table.recreate_rows_no_unselect();
+    /// }
+    /// ```
+    pub const fn set_defer_header_sort(&mut self, status: bool) {
+        self.defer_header_sort = status;
+    }
 
-                if resp.drag_started() {
-                    // If CTRL is not pressed down and the mouse right click is not pressed, unselect all cells
-                    // Right click for context menu
-                    if !ui.ctx().input(|i| i.modifiers.ctrl)
-                        && !ui.ctx().input(|i| i.pointer.secondary_clicked())
-                    {
-                        self.unselect_all();
-                    }
-                    self.drag_started_on = Some((row_data.id, column_name.clone()));
-                }
+    /// Returns whether the sort was changed by a header click and the rows have not been
+    /// recreated since. Only set when [`set_defer_header_sort`](#method.set_defer_header_sort)
+    /// is enabled.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// if table.is_sort_pending() {
+    ///     table.recreate_rows();
+    /// }
+    /// ```
+    pub const fn is_sort_pending(&self) -> bool {
+        self.sort_pending
+    }
 
-                let pointer_released = ui.input(|a| a.pointer.primary_released());
+    /// Resets the sort to the default column in ascending order and recreates the rows.
+    ///
+    /// The default column is `F::default()`, which is what the table is sorted by when created.
+    /// The selection is cleared.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// if ui.button("Reset sort").clicked() {
+    ///     table.reset_sort();
+    /// }
+    /// ```
+    pub fn reset_sort(&mut self) {
+        self.sorted_by = F::default();
+        self.sort_order = SortOrder::default();
+        self.unselect_all();
+        self.recreate_rows();
+    }
 
-                if pointer_released {
-                    self.last_active_row = None;
-                    self.last_active_column = None;
-                    self.drag_started_on = None;
-                    self.beyond_drag_point = false;
-                }
+    /// Sets a second column to order the rows that are equal in the sorted column.
+    ///
+    /// The tiebreaker column is always sorted in ascending order. Rows that are still equal are
+    /// ordered by their ID, so the displayed order stays the same across reloads even when the
+    /// sorted column has few distinct values. Changes are applied when the rows are recreated.
+    ///
+    /// # Parameters:
+    /// - `column`: The column to break ties with, or `None` to only use the row IDs.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_tiebreaker(Some(ColumnName::Name));
+    /// table.recreate_rows();
+    /// ```
+    pub fn set_tiebreaker(&mut self, column: Option<F>) {
+        self.tiebreaker = column;
+    }
 
-                if resp.clicked() {
-                    // If CTRL is not pressed down and the mouse right click is not pressed, unselect all cells
-                    if !ui.ctx().input(|i| i.modifiers.ctrl)
-                        && !ui.ctx().input(|i| i.pointer.secondary_clicked())
-                    {
-                        self.unselect_all();
-                    }
-                    self.select_single_row_cell(row_data.id, column_name);
-                }
+    /// Makes sorting and searching run on the current thread instead of the rayon thread pool.
+    ///
+    /// Useful where threads are unavailable or unwanted, such as WASM without thread support.
+    /// Parallel processing is used by default.
+    ///
+    /// # Returns:
+    /// - `Self`: The modified table running on a single thread.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .single_threaded();
+    /// ```
+    #[must_use]
+    pub const fn single_threaded(mut self) -> Self {
+        self.single_threaded = true;
+        self
+    }
 
-                if ui.ui_contains_pointer() && self.drag_started_on.is_some() {
-                    if let Some(drag_start) = self.drag_started_on.as_ref() {
-                        // Only call drag either when not on the starting drag row/column or went beyond the
-                        // drag point at least once. Otherwise normal click would be considered as drag
-                        if drag_start.0 != row_data.id
-                            || &drag_start.1 != column_name
-                            || self.beyond_drag_point
-                        {
-                            let is_ctrl_pressed = ui.ctx().input(|i| i.modifiers.ctrl);
-                            self.select_dragged_row_cell(row_data.id, column_name, is_ctrl_pressed);
-                        }
-                    }
-                }
-            });
-        }
+    /// Sets whether sorting and searching should run on the current thread instead of the
+    /// rayon thread pool.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to run on a single thread, `false` to process in parallel.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_single_threaded(cfg!(target_arch = "wasm32"));
+    /// ```
+    pub const fn set_single_threaded(&mut self, status: bool) {
+        self.single_threaded = status;
     }
 
-    /// Returns the total number of rows currently being displayed in the UI.
+    /// Makes row navigation wrap around, so moving down from the last row goes to the first
+    /// row and moving up from the first row goes to the last row.
+    ///
+    /// Row navigation stops at the first and last row by default, like a spreadsheet. Columns
+    /// always wrap around, so enabling this makes rows and columns behave the same, which suits
+    /// list-like tables. See [`adjacent_row`](#method.adjacent_row).
     ///
     /// # Returns:
-    /// - `usize`: The number of rows that are formatted and ready for display.
-    pub fn total_displayed_rows(&self) -> usize {
-        self.formatted_rows.len()
+    /// - `Self`: The modified table with row navigation wrapping around.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .navigation_wrap();
+    /// ```
+    #[must_use]
+    pub const fn navigation_wrap(mut self) -> Self {
+        self.navigation_wrap = true;
+        self
     }
 
-    /// Returns the total number of rows in the table (both displayed and non-displayed).
+    /// Sets whether row navigation should wrap around at the first and last row.
     ///
-    /// # Returns:
-    /// - `usize`: The total number of rows stored in the table, regardless of whether they are being displayed or not.
-    pub fn total_rows(&self) -> usize {
-        self.rows.len()
+    /// # Parameters:
+    /// - `status`: `true` to wrap around, `false` to stop at the first and last row.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_navigation_wrap(true);
+    /// ```
+    pub const fn set_navigation_wrap(&mut self, status: bool) {
+        self.navigation_wrap = status;
     }
 
-    /// Provides a reference to the rows currently being displayed in the UI.
+    /// Makes each column remember the sort order it was last sorted with.
+    ///
+    /// By default, switching the sort to another column always sorts it in ascending order. When
+    /// enabled, switching back to a column restores the order it was sorted with before.
     ///
     /// # Returns:
-    /// - `&Vec<SelectableRow<Row, F>>`: A reference to the vector of formatted rows ready for display.
-    pub const fn get_displayed_rows(&self) -> &Vec<SelectableRow<Row, F>> {
-        &self.formatted_rows
+    /// - `Self`: The modified table with sort direction memory enabled.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .remember_sort_direction();
+    /// ```
+    #[must_use]
+    pub const fn remember_sort_direction(mut self) -> Self {
+        self.remember_sort_direction = true;
+        self
     }
 
-    /// Provides a reference to all rows in the table, regardless of whether they are displayed.
+    /// Sets whether each column should remember the sort order it was last sorted with.
     ///
-    /// # Returns:
-    /// - `&HashMap<i64, SelectableRow<Row, F>>`: A reference to the entire collection of rows in the table.
-    pub const fn get_all_rows(&self) -> &HashMap<i64, SelectableRow<Row, F>> {
-        &self.rows
+    /// # Parameters:
+    /// - `status`: `true` to remember the sort order of each column, `false` to always start
+    ///   in ascending order.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_remember_sort_direction(true);
+    /// ```
+    pub fn set_remember_sort_direction(&mut self, status: bool) {
+        self.remember_sort_direction = status;
+        if !status {
+            self.sort_directions.clear();
+        }
     }
 
-    /// Adds a serial column to the table.
+    /// Disables sorting and displays the rows in the order they were added.
     ///
-    /// The serial column is automatically generated and displayed at the very left of the table.
-    /// It shows the row number (starting from 1) for each row.
+    /// Rows are never compared with [`ColumnOrdering`] and clicking on a header does not change
+    /// the sort. Useful for append-only tables such as logs.
     ///
     /// # Returns:
-    /// - `Self`: The modified table with the serial column enabled.
+    /// - `Self`: The modified table with sorting disabled.
     ///
     /// # Example:
     /// ```rust,ignore
     /// let table = SelectableTable::new(vec![col1, col2, col3])
-    ///     .config(my_config).serial_column();
+    ///     .no_sort();
     /// ```
     #[must_use]
-    pub const fn serial_column(mut self) -> Self {
-        self.add_serial_column = true;
+    pub const fn no_sort(mut self) -> Self {
+        self.sorting_enabled = false;
         self
     }
 
@@ -780,3 +3268,34 @@ where
         self
     }
 }
+
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>
+        + AllColumns,
+    Conf: Default,
+{
+    /// Creates a new `SelectableTable` with every column provided by the [`AllColumns`]
+    /// implementation of the column type.
+    ///
+    /// # Returns:
+    /// - A new instance of `SelectableTable`.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table: SelectableTable<MyRow, ColumnName, Config> = SelectableTable::with_all_columns();
+    /// ```
+    #[must_use]
+    pub fn with_all_columns() -> Self {
+        Self::new(F::all_columns())
+    }
+}
@@ -0,0 +1,71 @@
+use std::cmp::Ordering;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Takes the run of digits at the start of the iterator, without the leading zeros
+fn take_number(chars: &mut Peekable<Chars>) -> String {
+    let mut number = String::new();
+    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+        if number.is_empty() && c == '0' {
+            continue;
+        }
+        number.push(c);
+    }
+    number
+}
+
+/// Compares two strings the way a human would, treating runs of digits as numbers so `file2`
+/// is placed before `file10`.
+///
+/// Everything other than the digits is compared character by character, so the comparison is
+/// case-sensitive. Lowercase both strings first for a case-insensitive order.
+///
+/// # Parameters:
+/// - `a`: The first string.
+/// - `b`: The second string.
+///
+/// # Returns:
+/// - `Ordering`: The ordering of `a` relative to `b`.
+///
+/// # Example:
+/// ```rust,ignore
+/// impl ColumnOrdering<MyRow> for ColumnName {
+///     fn order_by(&self, row_1: &MyRow, row_2: &MyRow) -> Ordering {
+///         match self {
+///             ColumnName::File => natural_cmp(&row_1.file, &row_2.file),
+///             // ...
+///         }
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+
+    loop {
+        let ordering = match (a_chars.peek().copied(), b_chars.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a_char), Some(b_char)) if a_char.is_ascii_digit() && b_char.is_ascii_digit() => {
+                let a_number = take_number(&mut a_chars);
+                let b_number = take_number(&mut b_chars);
+                // Without the leading zeros, the longer number is always the bigger one
+                a_number
+                    .len()
+                    .cmp(&b_number.len())
+                    .then_with(|| a_number.cmp(&b_number))
+            }
+            (Some(a_char), Some(b_char)) => {
+                a_chars.next();
+                b_chars.next();
+                a_char.cmp(&b_char)
+            }
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use egui::Color32;
+use std::hash::Hash;
+use std::time::Duration;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableTable};
+
+/// A temporary highlight on a row that fades out over time
+pub struct RowFlash {
+    /// The color the flash starts with
+    pub color: Color32,
+    /// How long it takes for the flash to fade out completely
+    pub duration: Duration,
+    /// The time the flash was first shown. `None` until the next frame
+    pub started_at: Option<f64>,
+    /// The faded color to paint in the current frame
+    pub current_color: Color32,
+}
+
+/// Functions related to temporarily highlighting rows
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Briefly highlights a row with a background color that fades out over the given duration.
+    ///
+    /// Useful for drawing attention to a row that was just updated. Calling this again on a
+    /// flashing row restarts the flash.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row to highlight.
+    /// - `color`: The starting background color of the highlight.
+    /// - `duration`: How long it takes for the highlight to fade out.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.flash_row(row_id, Color32::YELLOW, Duration::from_secs(1));
+    /// ```
+    pub fn flash_row(&mut self, id: i64, color: Color32, duration: Duration) {
+        self.flashing_rows.insert(
+            id,
+            RowFlash {
+                color,
+                duration,
+                started_at: None,
+                current_color: color,
+            },
+        );
+    }
+
+    /// Update the faded color of each flashing row and remove the expired ones. Returns whether
+    /// any row is still flashing
+    pub(crate) fn update_row_flashes(&mut self, time: f64) -> bool {
+        self.flashing_rows.retain(|_, flash| {
+            let started_at = *flash.started_at.get_or_insert(time);
+            let duration = flash.duration.as_secs_f64();
+
+            let elapsed = time - started_at;
+            if elapsed >= duration {
+                return false;
+            }
+
+            let remaining = 1.0 - elapsed / duration;
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                flash.current_color = flash.color.gamma_multiply(remaining as f32);
+            }
+            true
+        });
+        !self.flashing_rows.is_empty()
+    }
+
+    /// The faded flash color of the row in the current frame, if the row is flashing
+    pub(crate) fn row_flash_color(&self, id: i64) -> Option<Color32> {
+        self.flashing_rows.get(&id).map(|flash| flash.current_color)
+    }
+}
@@ -0,0 +1,90 @@
+use std::any::Any;
+use std::hash::Hash;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableTable};
+
+/// Functions related to storing view specific values for each row
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Attaches a value to a row, replacing any value attached before.
+    ///
+    /// Meant for view specific state such as an expanded flag, a tag or an annotation that does
+    /// not belong in the row data. The value is kept by row ID, so it survives
+    /// [`recreate_rows`](#method.recreate_rows), and is removed when the rows are cleared.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row.
+    /// - `value`: The value to attach.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_row_meta(row_id, Color32::RED);
+    /// ```
+    pub fn set_row_meta<T: Any + Send>(&mut self, id: i64, value: T) {
+        self.row_meta.insert(id, Box::new(value));
+    }
+
+    /// Returns the value attached to the row with [`set_row_meta`](#method.set_row_meta), if
+    /// one was attached with the same type.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row.
+    ///
+    /// # Returns:
+    /// - `Option<&T>`: The attached value if it exists and is of type `T`.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// // Inside create_table_row
+    /// if let Some(color) = table.row_meta::<Color32>(row.id) {
+    ///     ui.painter().rect_filled(ui.max_rect(), 0.0, *color);
+    /// }
+    /// ```
+    pub fn row_meta<T: Any>(&self, id: i64) -> Option<&T> {
+        self.row_meta.get(&id)?.downcast_ref()
+    }
+
+    /// Returns a mutable reference to the value attached to the row, if one was attached with
+    /// the same type.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row.
+    ///
+    /// # Returns:
+    /// - `Option<&mut T>`: The attached value if it exists and is of type `T`.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// if let Some(expanded) = table.row_meta_mut::<bool>(row_id) {
+    ///     *expanded = !*expanded;
+    /// }
+    /// ```
+    pub fn row_meta_mut<T: Any>(&mut self, id: i64) -> Option<&mut T> {
+        self.row_meta.get_mut(&id)?.downcast_mut()
+    }
+
+    /// Removes the value attached to the row.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.remove_row_meta(row_id);
+    /// ```
+    pub fn remove_row_meta(&mut self, id: i64) {
+        self.row_meta.remove(&id);
+    }
+}
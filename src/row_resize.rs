@@ -0,0 +1,151 @@
+use egui::ahash::HashMap;
+use egui::{CursorIcon, Sense, Ui};
+use std::hash::Hash;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableTable};
+
+/// Height of the area at the bottom of each cell that can be dragged to resize the row
+const HANDLE_HEIGHT: f32 = 4.0;
+
+pub struct RowResize {
+    pub enabled: bool,
+    /// The height of each row that was resized, overriding the default row height
+    pub heights: HashMap<i64, f32>,
+    /// The minimum height a row can be resized to. Default: 10.0
+    pub min_height: f32,
+    /// The maximum height a row can be resized to. Default: 500.0
+    pub max_height: f32,
+}
+
+impl Default for RowResize {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            heights: HashMap::default(),
+            min_height: 10.0,
+            max_height: 500.0,
+        }
+    }
+}
+
+/// Functions related to resizing individual rows
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Lets the bottom edge of the cell be dragged to change the height of its row
+    pub(crate) fn handle_row_resize(&mut self, ui: &Ui, id: i64) {
+        let cell_rect = ui.max_rect();
+        let handle_rect = cell_rect.with_min_y(cell_rect.max.y - HANDLE_HEIGHT);
+        let resp = ui.interact(handle_rect, ui.id().with("row_resize"), Sense::drag());
+
+        if resp.hovered() || resp.dragged() {
+            ui.ctx().set_cursor_icon(CursorIcon::ResizeVertical);
+        }
+        if resp.dragged() {
+            let height = self.row_height_of(id) + resp.drag_delta().y;
+            let height = height.clamp(self.row_resize.min_height, self.row_resize.max_height);
+            self.row_resize.heights.insert(id, height);
+        }
+    }
+
+    /// Allows resizing each row by dragging the bottom edge of its cells.
+    ///
+    /// Only the dragged row changes height. The other rows keep the height set with
+    /// [`row_height`](#method.row_height).
+    ///
+    /// # Returns:
+    /// An updated instance of the table with row resizing enabled.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .resizable_rows();
+    /// ```
+    #[must_use]
+    pub const fn resizable_rows(mut self) -> Self {
+        self.row_resize.enabled = true;
+        self
+    }
+
+    /// Sets whether each row can be resized by dragging the bottom edge of its cells. Rows that
+    /// were already resized keep their height.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to allow resizing rows, `false` to disable it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_resizable_rows(true);
+    /// ```
+    pub const fn set_resizable_rows(&mut self, status: bool) {
+        self.row_resize.enabled = status;
+    }
+
+    /// Sets the minimum and maximum height a row can be resized to. Default: 10.0 to 500.0
+    ///
+    /// The limits are swapped if `min` is greater than `max`. A NaN limit leaves the current
+    /// limits unchanged and panics in debug builds.
+    ///
+    /// # Parameters:
+    /// - `min`: The minimum row height in pixels.
+    /// - `max`: The maximum row height in pixels.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_row_height_limits(20.0, 200.0);
+    /// ```
+    pub const fn set_row_height_limits(&mut self, min: f32, max: f32) {
+        debug_assert!(
+            !min.is_nan() && !max.is_nan(),
+            "Row height limits cannot be NaN"
+        );
+        if min.is_nan() || max.is_nan() {
+            return;
+        }
+
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        self.row_resize.min_height = min;
+        self.row_resize.max_height = max;
+    }
+
+    /// Returns the height of the row, including any height it was resized to.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row.
+    ///
+    /// # Returns:
+    /// - `f32`: The resized height of the row, or the default row height.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let height = table.row_height_of(row_id);
+    /// ```
+    pub fn row_height_of(&self, id: i64) -> f32 {
+        self.row_resize
+            .heights
+            .get(&id)
+            .copied()
+            .unwrap_or(self.row_height)
+    }
+
+    /// Resets every resized row back to the default row height.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.reset_row_heights();
+    /// ```
+    pub fn reset_row_heights(&mut self) {
+        self.row_resize.heights.clear();
+    }
+}
@@ -1,8 +1,15 @@
-use egui::ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
-use egui::Ui;
+use egui::ahash::{HashMap, HashSet, HashSetExt};
+use egui::{Context, Ui};
+use rayon::prelude::*;
 use std::hash::Hash;
+use std::ops::{Range, RangeInclusive};
+use std::time::Duration;
 
-use crate::{ColumnOperations, ColumnOrdering, SelectableRow, SelectableTable};
+use crate::copy_format::dedup_rows;
+use crate::{
+    ColumnOperations, ColumnOrdering, CopyFormatter, SelectableRow, SelectableTable, SelectionMode,
+    SelectionState,
+};
 
 /// Functions related to selection of rows and columns
 #[allow(clippy::too_many_lines)]
@@ -21,6 +28,11 @@ where
     Conf: Default,
 {
     pub(crate) fn select_single_row_cell(&mut self, id: i64, column_name: &F) {
+        if !self.active_rows.contains(&id) && self.is_selection_full() {
+            self.selection_truncated = true;
+            return;
+        }
+
         self.active_columns.insert(column_name.clone());
         self.active_rows.insert(id);
 
@@ -45,12 +57,149 @@ where
         self.active_rows.insert(id);
     }
 
+    /// Unselect the cell, or the whole row if full row selection is enabled
+    pub(crate) fn unselect_single_row_cell(&mut self, id: i64, column_name: &F) {
+        if self.select_full_row {
+            self.unselect_row(id);
+        } else {
+            self.deselect_cell(id, column_name);
+        }
+    }
+
+    /// Unselect every selected cell of the row
+    pub(crate) fn unselect_row(&mut self, id: i64) {
+        let Some(index) = self.indexed_ids.get(&id) else {
+            return;
+        };
+        let selected_columns: Vec<F> = self.formatted_rows[*index]
+            .selected_columns
+            .iter()
+            .cloned()
+            .collect();
+        for column in &selected_columns {
+            self.deselect_cell(id, column);
+        }
+    }
+
+    /// Unselect every cell in the rectangle between the drag starting cell and the given cell,
+    /// starting from the selection before the drag so cells that left the rectangle are
+    /// selected again
+    pub(crate) fn subtract_dragged_row_cell(&mut self, id: i64, column_name: &F) {
+        let id = if self.single_row_drag {
+            self.drag_started_on
+                .as_ref()
+                .map_or(id, |(start_id, _)| *start_id)
+        } else {
+            id
+        };
+
+        if self.last_active_row == Some(id) && self.last_active_column == Some(column_name.clone())
+        {
+            return;
+        }
+        self.last_active_row = Some(id);
+        self.last_active_column = Some(column_name.clone());
+        self.beyond_drag_point = true;
+
+        let (Some(base), Some((start_id, start_column))) = (
+            self.subtract_drag_base.as_ref(),
+            self.drag_started_on.as_ref(),
+        ) else {
+            return;
+        };
+        let (Some(start_index), Some(current_index)) =
+            (self.indexed_ids.get(start_id), self.indexed_ids.get(&id))
+        else {
+            return;
+        };
+        let row_range = *start_index.min(current_index)..=*start_index.max(current_index);
+
+        let columns: Vec<F> = if self.select_full_row {
+            self.all_columns.clone()
+        } else {
+            let start_num = self.column_to_num(start_column);
+            let current_num = self.column_to_num(column_name);
+            self.all_columns[start_num.min(current_num)..=start_num.max(current_num)].to_vec()
+        };
+
+        self.active_rows.clear();
+        self.active_columns.clear();
+        for (base_id, base_columns) in base {
+            let Some(index) = self.indexed_ids.get(base_id) else {
+                continue;
+            };
+            let row = &mut self.formatted_rows[*index];
+            row.selected_columns.clone_from(base_columns);
+            if row_range.contains(index) {
+                for column in &columns {
+                    row.selected_columns.remove(column);
+                }
+            }
+            if !row.selected_columns.is_empty() {
+                self.active_rows.insert(*base_id);
+                self.active_columns
+                    .extend(row.selected_columns.iter().cloned());
+            }
+        }
+    }
+
+    /// Select every cell in the rectangle between the selection anchor and the given cell
+    pub(crate) fn select_anchor_range(&mut self, id: i64, column_name: &F) {
+        let anchor = self
+            .selection_anchor
+            .as_ref()
+            .and_then(|(anchor_id, anchor_column)| {
+                let anchor_index = self.indexed_ids.get(anchor_id)?;
+                let anchor_column_num = self.column_number.get(anchor_column)?;
+                Some((*anchor_index, *anchor_column_num))
+            });
+        let Some((anchor_index, anchor_column_num)) = anchor else {
+            // The anchor row or column is no longer displayed
+            self.select_single_row_cell(id, column_name);
+            return;
+        };
+        let target_index = *self.indexed_ids.get(&id).expect("target_index not found");
+
+        let columns = if self.select_full_row {
+            self.all_columns.clone()
+        } else {
+            let target_column_num = self.column_to_num(column_name);
+            let start = anchor_column_num.min(target_column_num);
+            let end = anchor_column_num.max(target_column_num);
+            self.all_columns[start..=end].to_vec()
+        };
+
+        let start = anchor_index.min(target_index);
+        let end = anchor_index.max(target_index);
+        let max_selection = self.max_selection.unwrap_or(usize::MAX);
+        for row in &mut self.formatted_rows[start..=end] {
+            let is_new_row = !self.active_rows.contains(&row.id);
+            if is_new_row && self.active_rows.len() >= max_selection {
+                self.selection_truncated = true;
+                break;
+            }
+            self.active_rows.insert(row.id);
+            row.selected_columns.extend(columns.iter().cloned());
+        }
+        self.active_columns.extend(columns);
+    }
+
     pub(crate) fn select_dragged_row_cell(
         &mut self,
         id: i64,
         column_name: &F,
         is_ctrl_pressed: bool,
     ) {
+        // When constrained to a single row, only the column changes. Treat the pointer as if it
+        // is still on the row where the drag started
+        let id = if self.single_row_drag {
+            self.drag_started_on
+                .as_ref()
+                .map_or(id, |(start_id, _)| *start_id)
+        } else {
+            id
+        };
+
         // If both same then the mouse is still on the same column on the same row so nothing to process
         if self.last_active_row == Some(id) && self.last_active_column == Some(column_name.clone())
         {
@@ -61,6 +210,22 @@ where
             return;
         }
 
+        // Only a single cell can be selected so dragging moves the selection to the current cell
+        if self.selection_mode == SelectionMode::Single {
+            self.beyond_drag_point = true;
+            self.unselect_all();
+            self.select_single_row_cell(id, column_name);
+            self.last_active_row = Some(id);
+            self.last_active_column = Some(column_name.clone());
+            return;
+        }
+
+        // Dragging into a new row is not allowed once the selection is at the limit
+        if !self.active_rows.contains(&id) && self.is_selection_full() {
+            self.selection_truncated = true;
+            return;
+        }
+
         self.active_columns.insert(column_name.clone());
         self.beyond_drag_point = true;
 
@@ -235,6 +400,14 @@ where
             current_row.selected_columns.is_empty()
         };
 
+        if !unselected_row
+            && !self.active_rows.contains(&current_row.id)
+            && self.is_selection_full()
+        {
+            self.selection_truncated = true;
+            return;
+        }
+
         let target_row = self
             .formatted_rows
             .get_mut(index)
@@ -322,182 +495,1299 @@ where
             target_row.selected_columns.clear();
         }
         self.active_columns.clear();
+        self.hidden_selection.clear();
+        self.selection_truncated = false;
         self.last_active_row = None;
         self.last_active_column = None;
         self.active_rows.clear();
     }
 
+    /// Unselects a single cell while keeping the rest of the selection.
+    ///
+    /// The change is applied to the displayed rows directly and is visible in the next frame
+    /// without recreating the rows. Does nothing if the cell is not selected or the row is not
+    /// displayed.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row.
+    /// - `column`: The column of the cell to unselect.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.deselect_cell(row_id, &ColumnName::Name);
+    /// ```
+    pub fn deselect_cell(&mut self, id: i64, column: &F) {
+        let Some(index) = self.indexed_ids.get(&id) else {
+            return;
+        };
+        let row = &mut self.formatted_rows[*index];
+        if !row.selected_columns.remove(column) {
+            return;
+        }
+
+        if row.selected_columns.is_empty() {
+            self.active_rows.remove(&id);
+        }
+
+        // Keep the column active only while another row still has it selected
+        let indexed_ids = &self.indexed_ids;
+        let still_selected = self.active_rows.iter().any(|active_id| {
+            indexed_ids.get(active_id).is_some_and(|index| {
+                self.formatted_rows[*index]
+                    .selected_columns
+                    .contains(column)
+            })
+        });
+        if !still_selected {
+            self.active_columns.remove(column);
+        }
+    }
+
+    /// Clears the selection in response to a click outside the table.
+    ///
+    /// The table cannot detect clicks outside of it, so call this when such a click is
+    /// detected. A common pattern is to check [`Response::clicked_elsewhere`](egui::Response::clicked_elsewhere)
+    /// on the response of the area containing the table.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let response = ui.scope(|ui| table.show_ui(ui, |builder| builder)).response;
+    /// if response.clicked_elsewhere() {
+    ///     table.handle_outside_click();
+    /// }
+    /// ```
+    pub fn handle_outside_click(&mut self) {
+        self.unselect_all();
+    }
+
     /// Selects all rows and columns in the table.
     ///
-    /// After calling this method, all rows will have all columns selected.
+    /// After calling this method, all rows will have all columns selected. Does nothing if the
+    /// selection mode is [`SelectionMode::Single`].
     ///
     /// # Example:
     /// ```rust,ignore
     /// table.select_all(); // Selects all rows and columns.
     /// ```
     pub fn select_all(&mut self) {
-        let mut all_rows = Vec::new();
+        if self.selection_mode == SelectionMode::Single {
+            return;
+        }
+
+        let max_selection = self.max_selection.unwrap_or(usize::MAX);
 
         for row in &mut self.formatted_rows {
+            let is_new_row = !self.active_rows.contains(&row.id);
+            if is_new_row && self.active_rows.len() >= max_selection {
+                self.selection_truncated = true;
+                break;
+            }
+            self.active_rows.insert(row.id);
             row.selected_columns.extend(self.all_columns.clone());
-            all_rows.push(row.id);
         }
 
         self.active_columns.extend(self.all_columns.clone());
-        self.active_rows.extend(all_rows);
         self.last_active_row = None;
         self.last_active_column = None;
     }
 
-    /// Retrieves the currently selected rows.
-    ///
-    /// This method returns a vector of the rows that have one or more columns selected.
+    /// Selects the rows at the given positions of the current display order, in addition to the
+    /// current selection.
     ///
-    /// If the `select_full_row` flag is enabled, it will ensure that all columns are selected for
-    /// each active row.
+    /// Unlike selecting by ID, the range is positional, which matches what a scrollbar or a
+    /// minimap interaction produces. The range is clamped to the displayed rows. Does nothing if
+    /// the selection mode is [`SelectionMode::Single`].
     ///
-    /// # Returns:
-    /// A `Vec` of `SelectableRow` instances that are currently selected.
+    /// # Parameters:
+    /// - `range`: The display positions of the rows to select.
+    /// - `columns`: The columns to select in each row, or `None` for all columns.
     ///
     /// # Example:
     /// ```rust,ignore
-    /// let selected_rows = table.get_selected_rows();
+    /// table.select_index_range(10..20, None);
+    /// table.select_index_range(0..5, Some(&[ColumnName::Name]));
     /// ```
-    pub fn get_selected_rows(&mut self) -> Vec<SelectableRow<Row, F>> {
-        let mut selected_rows = Vec::new();
-        if self.select_full_row {
-            self.active_columns.extend(self.all_columns.clone());
+    pub fn select_index_range(&mut self, range: Range<usize>, columns: Option<&[F]>) {
+        if self.selection_mode == SelectionMode::Single {
+            return;
         }
 
-        // Cannot use active rows to iter as that does not maintain any proper format
-        for row in &self.formatted_rows {
-            if row.selected_columns.is_empty() {
-                continue;
-            }
-            selected_rows.push(row.clone());
+        let end = range.end.min(self.formatted_rows.len());
+        let start = range.start.min(end);
+        let columns = columns.map_or_else(|| self.all_columns.clone(), <[F]>::to_vec);
+        let max_selection = self.max_selection.unwrap_or(usize::MAX);
 
-            // We already got all the active rows if this matches
-            if selected_rows.len() == self.active_rows.len() {
+        for row in &mut self.formatted_rows[start..end] {
+            let is_new_row = !self.active_rows.contains(&row.id);
+            if is_new_row && self.active_rows.len() >= max_selection {
+                self.selection_truncated = true;
                 break;
             }
+            self.active_rows.insert(row.id);
+            row.selected_columns.extend(columns.iter().cloned());
         }
-        selected_rows
+        self.active_columns.extend(columns);
     }
 
-    /// Copies selected cells to the system clipboard in a tabular format.
+    /// Selects every displayed cell where the predicate returns `true`, in addition to the
+    /// current selection.
     ///
-    /// This method copies only the selected cells from each row to the clipboard, and ensures
-    /// that the column widths align for better readability when pasted into a text editor or spreadsheet.
+    /// The predicate is called with the column and the row data of each cell, in parallel. With
+    /// full row selection enabled, a single matching cell selects the whole row. In
+    /// [`SelectionMode::Single`], only the first matching cell in display order is selected.
     ///
     /// # Parameters:
-    /// - `ui`: The UI context used for clipboard interaction.
+    /// - `predicate`: A closure returning whether the cell of the column in the row should be
+    ///   selected.
     ///
     /// # Example:
     /// ```rust,ignore
-    /// table.copy_selected_cells(&mut ui);
+    /// // Select every negative amount
+    /// table.select_cells_where(|column, row| {
+    ///     *column == ColumnName::Amount && row.amount < 0
+    /// });
     /// ```
-    pub fn copy_selected_cells(&mut self, ui: &mut Ui) {
-        let mut selected_rows = Vec::new();
-        if self.select_full_row {
-            self.active_columns.extend(self.all_columns.clone());
+    pub fn select_cells_where<P>(&mut self, predicate: P)
+    where
+        P: Fn(&F, &Row) -> bool + Sync,
+    {
+        if self.selection_mode == SelectionMode::Single {
+            let first_match = self.formatted_rows.iter().find_map(|row| {
+                self.all_columns
+                    .iter()
+                    .find(|column| predicate(column, &row.row_data))
+                    .map(|column| (row.id, column.clone()))
+            });
+
+            if let Some((id, column)) = first_match {
+                self.unselect_all();
+                self.select_single_row_cell(id, &column);
+            }
+            return;
         }
 
-        let mut column_max_length = HashMap::new();
+        let all_columns = &self.all_columns;
 
-        // Iter through all the rows and find the rows that have at least one column as selected
-        // Keep track of the biggest length of a value of a column
-        // active rows cannot be used here because hashset does not maintain an order.
-        // So itering will give the rows in a different order than what is shown in the ui
-        for row in &self.formatted_rows {
-            if row.selected_columns.is_empty() {
-                continue;
-            }
+        let find_matched = |(index, row): (usize, &SelectableRow<Row, F>)| {
+            let matched_columns: Vec<F> = all_columns
+                .iter()
+                .filter(|column| predicate(column, &row.row_data))
+                .cloned()
+                .collect();
 
-            for column in &self.active_columns {
-                if row.selected_columns.contains(column) {
-                    let column_text = column.column_text(&row.row_data);
-                    let field_length = column_text.len();
-                    let entry = column_max_length.entry(column).or_insert(0);
-                    if field_length > *entry {
-                        column_max_length.insert(column, field_length);
-                    }
-                }
+            if matched_columns.is_empty() {
+                None
+            } else {
+                Some((index, matched_columns))
             }
-            selected_rows.push(row);
-            // We already got all the active rows if this matches
-            if selected_rows.len() == self.active_rows.len() {
+        };
+        let matched: Vec<(usize, Vec<F>)> = if self.single_threaded {
+            self.formatted_rows
+                .iter()
+                .enumerate()
+                .filter_map(find_matched)
+                .collect()
+        } else {
+            self.formatted_rows
+                .par_iter()
+                .enumerate()
+                .filter_map(find_matched)
+                .collect()
+        };
+
+        // Selected in display order so the rows within the selection limit are the first ones
+        let max_selection = self.max_selection.unwrap_or(usize::MAX);
+        for (index, columns) in matched {
+            let row = &mut self.formatted_rows[index];
+            let is_new_row = !self.active_rows.contains(&row.id);
+            if is_new_row && self.active_rows.len() >= max_selection {
+                self.selection_truncated = true;
                 break;
             }
+            self.active_rows.insert(row.id);
+
+            if self.select_full_row {
+                row.selected_columns.extend(all_columns.iter().cloned());
+            } else {
+                row.selected_columns.extend(columns.iter().cloned());
+            }
+            self.active_columns.extend(columns);
+        }
+
+        if self.select_full_row && !self.active_rows.is_empty() {
+            self.active_columns.extend(self.all_columns.clone());
         }
+    }
 
-        let mut to_copy = String::new();
+    /// Whether the number of selected rows reached the limit set with
+    /// [`set_max_selection`](#method.set_max_selection)
+    pub(crate) fn is_selection_full(&self) -> bool {
+        self.max_selection
+            .is_some_and(|max_selection| self.active_rows.len() >= max_selection)
+    }
 
-        // Target is to ensure a fixed length after each column value of a row
-        // If for example highest len is 10 but the current row's
-        // column value is 5, we will add the column value and add 5 more space after that
-        // to ensure alignment
-        for row in selected_rows {
-            let mut ongoing_column = self.first_column();
-            let mut row_text = String::new();
-            loop {
-                if self.active_columns.contains(&ongoing_column)
-                    && row.selected_columns.contains(&ongoing_column)
-                {
-                    let column_text = ongoing_column.column_text(&row.row_data);
-                    row_text += &format!(
-                        "{:<width$}",
-                        column_text,
-                        width = column_max_length[&ongoing_column] + 1
-                    );
-                } else if self.active_columns.contains(&ongoing_column)
-                    && !row.selected_columns.contains(&ongoing_column)
-                {
-                    row_text += &format!(
-                        "{:<width$}",
-                        "",
-                        width = column_max_length[&ongoing_column] + 1
-                    );
-                }
-                if self.last_column() == ongoing_column {
-                    break;
-                }
-                ongoing_column = self.next_column(&ongoing_column);
+    /// Sets the maximum number of rows that can be selected at the same time.
+    ///
+    /// Once the limit is reached, clicks, drags and the selection methods such as
+    /// [`select_all`](#method.select_all) and [`set_selection`](#method.set_selection) stop
+    /// adding new rows. Methods selecting many rows at once keep the ones displayed first.
+    /// Useful for protecting the UI and the code using the selection from selecting millions
+    /// of rows by accident. Default: no limit.
+    ///
+    /// # Parameters:
+    /// - `max`: The maximum number of selected rows, or `None` for no limit.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_max_selection(Some(10_000));
+    /// ```
+    pub const fn set_max_selection(&mut self, max: Option<usize>) {
+        self.max_selection = max;
+    }
+
+    /// Returns whether a selection stopped early because it reached the limit set with
+    /// [`set_max_selection`](#method.set_max_selection). Reset when everything is unselected.
+    ///
+    /// # Returns:
+    /// - `bool`: `true` if some rows were left out of the selection.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// if table.selection_truncated() {
+    ///     ui.label("Selection limited to 10000 rows");
+    /// }
+    /// ```
+    pub const fn selection_truncated(&self) -> bool {
+        self.selection_truncated
+    }
+
+    /// Sets the cell that a SHIFT click selection starts from.
+    ///
+    /// SHIFT clicking a cell selects every cell in the rectangle between the anchor and the
+    /// clicked cell. The anchor is normally the last clicked cell, this allows starting the
+    /// range from elsewhere, such as after a custom header action.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row of the anchor cell.
+    /// - `column`: The column of the anchor cell.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_selection_anchor(row_id, ColumnName::Name);
+    /// ```
+    pub fn set_selection_anchor(&mut self, id: i64, column: F) {
+        self.selection_anchor = Some((id, column));
+    }
+
+    /// Removes the selection anchor, so the next SHIFT click selects only the clicked cell and
+    /// becomes the new anchor.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.clear_selection_anchor();
+    /// ```
+    pub fn clear_selection_anchor(&mut self) {
+        self.selection_anchor = None;
+    }
+
+    /// Returns the cell that a SHIFT click selection starts from, if there is one.
+    ///
+    /// # Returns:
+    /// - `Option<&(i64, F)>`: The row ID and column of the anchor cell.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let anchor = table.selection_anchor();
+    /// ```
+    pub const fn selection_anchor(&self) -> Option<&(i64, F)> {
+        self.selection_anchor.as_ref()
+    }
+
+    /// Replaces the current selection with exactly the given cells.
+    ///
+    /// Each row can have a different set of selected columns. Rows that are not currently
+    /// displayed and empty column sets are ignored. Useful for restoring a selection saved with
+    /// [`get_selection`](#method.get_selection) after the rows were reloaded.
+    ///
+    /// # Parameters:
+    /// - `cells`: A map of row IDs to the columns that should be selected in the row.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let selection = table.get_selection();
+    /// table.recreate_rows();
+    /// table.set_selection(selection);
+    /// ```
+    pub fn set_selection(&mut self, cells: HashMap<i64, HashSet<F>>) {
+        self.unselect_all();
+
+        let mut cells: Vec<(usize, i64, HashSet<F>)> = cells
+            .into_iter()
+            .filter(|(_, columns)| !columns.is_empty())
+            .filter_map(|(id, columns)| Some((*self.indexed_ids.get(&id)?, id, columns)))
+            .collect();
+        // Selected in display order so the rows within the selection limit are the first ones
+        cells.sort_unstable_by_key(|(index, _, _)| *index);
+
+        for (index, id, columns) in cells {
+            if self.is_selection_full() {
+                self.selection_truncated = true;
+                break;
             }
-            to_copy.push_str(&row_text);
-            to_copy.push('\n');
+            self.active_columns.extend(columns.iter().cloned());
+            self.formatted_rows[index].selected_columns = columns;
+            self.active_rows.insert(id);
         }
-
-        ui.ctx().output_mut(|i| i.copied_text = to_copy);
     }
 
-    /// Enables the selection of full rows in the table.
+    /// Replaces the current selection with a single cell or row and scrolls it into view on the
+    /// next frame.
     ///
-    /// After calling this method, selecting any column in a row will result in the entire row being selected.
+    /// Useful for going to a specific record, such as from a search result or a link.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row to select.
+    /// - `column`: The column of the cell to select, or `None` to select the whole row.
     ///
     /// # Returns:
-    /// A new instance of the table with full row selection enabled.
+    /// - `bool`: `true` if the row is displayed and was selected, `false` otherwise. The
+    ///   selection is cleared either way.
     ///
     /// # Example:
     /// ```rust,ignore
-    /// let table = SelectableTable::new(vec![col1, col2, col3])
-    ///     .select_full_row();
+    /// table.select_and_reveal(result_id, Some(ColumnName::Name));
     /// ```
-    #[must_use]
-    pub const fn select_full_row(mut self) -> Self {
-        self.select_full_row = true;
-        self
+    pub fn select_and_reveal(&mut self, id: i64, column: Option<F>) -> bool {
+        self.unselect_all();
+
+        let Some(index) = self.indexed_ids.get(&id).copied() else {
+            return false;
+        };
+
+        if let Some(column) = column {
+            self.select_single_row_cell(id, &column);
+            self.selection_anchor = Some((id, column));
+        } else {
+            self.active_columns.extend(self.all_columns.clone());
+            self.formatted_rows[index]
+                .selected_columns
+                .extend(self.all_columns.clone());
+            self.active_rows.insert(id);
+        }
+
+        self.scroll_to_index = Some(index);
+        true
     }
 
-    /// Sets whether the table should select full rows when a column is selected.
+    /// Returns the displayed row above or below the given row, for moving the selection with
+    /// the keyboard.
+    ///
+    /// At the first and last row this returns `None`, or the row at the other end if
+    /// [`navigation_wrap`](#method.navigation_wrap) is enabled.
     ///
     /// # Parameters:
-    /// - `status`: `true` to enable full row selection, `false` to disable it.
+    /// - `id`: The ID of the row to move from.
+    /// - `down`: `true` for the row below, `false` for the row above.
+    ///
+    /// # Returns:
+    /// - `Option<i64>`: The ID of the adjacent row, or `None` if there is none or the row is not
+    ///   displayed.
     ///
     /// # Example:
     /// ```rust,ignore
-    /// table.set_select_full_row(true); // Enable full row selection.
+    /// if ui.input(|i| i.key_pressed(Key::ArrowDown)) {
+    ///     if let Some(next_id) = table.adjacent_row(row_id, true) {
+    ///         table.select_and_reveal(next_id, None);
+    ///     }
+    /// }
     /// ```
-    pub fn set_select_full_row(&mut self, status: bool) {
-        self.select_full_row = status;
+    pub fn adjacent_row(&self, id: i64, down: bool) -> Option<i64> {
+        let index = *self.indexed_ids.get(&id)?;
+        let last_index = self.formatted_rows.len() - 1;
+
+        let target_index = match (down, index == last_index, index == 0) {
+            (true, false, _) => index + 1,
+            (false, _, false) => index - 1,
+            (true, true, _) if self.navigation_wrap => 0,
+            (false, _, true) if self.navigation_wrap => last_index,
+            _ => return None,
+        };
+        Some(self.formatted_rows[target_index].id)
+    }
+
+    /// Returns the selected columns of each selected row.
+    ///
+    /// # Returns:
+    /// - `HashMap<i64, HashSet<F>>`: A map of the selected row IDs to their selected columns.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let selection = table.get_selection();
+    /// ```
+    pub fn get_selection(&self) -> HashMap<i64, HashSet<F>> {
+        self.active_rows
+            .iter()
+            .filter_map(|id| {
+                let index = self.indexed_ids.get(id)?;
+                let row = &self.formatted_rows[*index];
+                (!row.selected_columns.is_empty()).then(|| (*id, row.selected_columns.clone()))
+            })
+            .collect()
+    }
+
+    /// Encodes the IDs of the selected rows into a short URL safe text.
+    ///
+    /// Consecutive IDs are stored as a range, so selecting rows 0 to 999 and row 1500 produces
+    /// `0-999.1500`. Only the rows are encoded, not which columns are selected. Restore it with
+    /// [`apply_selection_token`](#method.apply_selection_token).
+    ///
+    /// # Returns:
+    /// - `String`: The encoded selection. Empty if nothing is selected.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let link = format!("https://example.com/table?selection={}", table.selection_token());
+    /// ```
+    pub fn selection_token(&self) -> String {
+        let mut ids: Vec<i64> = self.active_rows.iter().copied().collect();
+        ids.sort_unstable();
+
+        let mut ranges: Vec<(i64, i64)> = Vec::new();
+        for id in ids {
+            match ranges.last_mut() {
+                Some((_, end)) if *end + 1 == id => *end = id,
+                _ => ranges.push((id, id)),
+            }
+        }
+
+        ranges
+            .iter()
+            .map(|(start, end)| {
+                if start == end {
+                    start.to_string()
+                } else {
+                    format!("{start}-{end}")
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(".")
+    }
+
+    /// Replaces the current selection with the rows encoded by
+    /// [`selection_token`](#method.selection_token).
+    ///
+    /// All columns of the encoded rows are selected. In [`SelectionMode::Single`], only the
+    /// first column of the first encoded row in display order is selected. Rows that are not
+    /// currently displayed and invalid parts of the token are ignored.
+    ///
+    /// # Parameters:
+    /// - `token`: The encoded selection.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.apply_selection_token("0-999.1500");
+    /// ```
+    pub fn apply_selection_token(&mut self, token: &str) {
+        let all_columns: HashSet<F> = self.all_columns.iter().cloned().collect();
+        let mut cells = HashMap::default();
+
+        for part in token.split('.') {
+            let range: Option<(i64, i64)> = match part.split_once('-') {
+                Some((start, end)) => start.parse().ok().zip(end.parse().ok()),
+                None => part.parse().ok().map(|id| (id, id)),
+            };
+            let Some((start, end)) = range else {
+                continue;
+            };
+            // IDs above the last used one cannot exist, so a huge range does not loop forever
+            for id in start.max(0)..=end.min(self.last_id_used) {
+                if self.indexed_ids.contains_key(&id) {
+                    cells.insert(id, all_columns.clone());
+                }
+            }
+        }
+
+        if self.selection_mode == SelectionMode::Single {
+            let first_index = cells
+                .keys()
+                .filter_map(|id| self.indexed_ids.get(id))
+                .min()
+                .copied();
+
+            self.unselect_all();
+            if let (Some(index), Some(column)) = (first_index, self.first_column()) {
+                let id = self.formatted_rows[index].id;
+                self.select_single_row_cell(id, &column);
+            }
+            return;
+        }
+        self.set_selection(cells);
+    }
+
+    /// Modifies the data of every selected row, in display order.
+    ///
+    /// Both the stored and the displayed rows are updated, so the change is visible right away
+    /// and survives the rows being recreated. The modified rows are marked as dirty, see
+    /// [`is_dirty`](#method.is_dirty).
+    ///
+    /// # Parameters:
+    /// - `edit`: A closure that modifies the data of a selected row.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.for_each_selected_mut(|row| row.done = true);
+    /// ```
+    pub fn for_each_selected_mut<Fn>(&mut self, mut edit: Fn)
+    where
+        Fn: FnMut(&mut Row),
+    {
+        let mut indices: Vec<usize> = self
+            .active_rows
+            .iter()
+            .filter_map(|id| self.indexed_ids.get(id).copied())
+            .collect();
+        indices.sort_unstable();
+
+        let view_transform = self.view_transform.as_deref();
+        for index in indices {
+            let displayed_row = &mut self.formatted_rows[index];
+            let Some(row) = self.rows.get_mut(&displayed_row.id) else {
+                continue;
+            };
+            edit(&mut row.row_data);
+
+            displayed_row.row_data = Self::view_row(view_transform, row).row_data;
+            self.dirty_rows.insert(displayed_row.id);
+        }
+    }
+
+    /// Returns whether any row is selected.
+    ///
+    /// Cheaper than counting the selected rows when only the emptiness matters, such as for
+    /// enabling buttons that act on the selection.
+    ///
+    /// # Returns:
+    /// - `bool`: `true` if at least one row is selected.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// ui.add_enabled(table.has_selection(), Button::new("Delete Selected"));
+    /// ```
+    pub fn has_selection(&self) -> bool {
+        !self.active_rows.is_empty()
+    }
+
+    /// Rebuilds the bookkeeping of the selected rows and columns from the displayed rows.
+    ///
+    /// Changing the `selected_columns` of the displayed rows with
+    /// [`modify_shown_row`](#method.modify_shown_row) is not tracked by the table, which makes
+    /// copy and select all miss cells that look selected. Call this afterward to make the
+    /// selection consistent again.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.modify_shown_row(|rows, indexed_ids| {
+    ///     let index = indexed_ids[&row_id];
+    ///     rows[index].selected_columns.insert(ColumnName::Name);
+    /// });
+    /// table.resync_selection();
+    /// ```
+    pub fn resync_selection(&mut self) {
+        self.active_rows.clear();
+        self.active_columns.clear();
+
+        for row in &self.formatted_rows {
+            if row.selected_columns.is_empty() {
+                continue;
+            }
+            self.active_rows.insert(row.id);
+            self.active_columns
+                .extend(row.selected_columns.iter().cloned());
+        }
+    }
+
+    /// Retrieves the currently selected rows.
+    ///
+    /// This method returns a vector of the rows that have one or more columns selected.
+    ///
+    /// If the `select_full_row` flag is enabled, it will ensure that all columns are selected for
+    /// each active row.
+    ///
+    /// # Returns:
+    /// A `Vec` of `SelectableRow` instances that are currently selected.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let selected_rows = table.get_selected_rows();
+    /// ```
+    pub fn get_selected_rows(&mut self) -> Vec<SelectableRow<Row, F>> {
+        let mut selected_rows = Vec::new();
+        if self.select_full_row {
+            self.active_columns.extend(self.all_columns.clone());
+        }
+
+        // Cannot use active rows to iter as that does not maintain any proper format
+        for row in &self.formatted_rows {
+            if row.selected_columns.is_empty() {
+                continue;
+            }
+            selected_rows.push(row.clone());
+
+            // We already got all the active rows if this matches
+            if selected_rows.len() == self.active_rows.len() {
+                break;
+            }
+        }
+        selected_rows
+    }
+
+    /// Returns references to the currently selected rows without cloning them.
+    ///
+    /// This method returns the displayed rows that have one or more columns selected, in the
+    /// order they are displayed. Selected rows hidden by a filter are not included.
+    ///
+    /// # Returns:
+    /// A `Vec` of references to the `SelectableRow` instances that are currently selected.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// for row in table.selected_rows() {
+    ///     ui.label(&row.row_data.name);
+    /// }
+    /// ```
+    pub fn selected_rows(&self) -> Vec<&SelectableRow<Row, F>> {
+        let mut indices: Vec<usize> = self
+            .active_rows
+            .iter()
+            .filter_map(|id| self.indexed_ids.get(id).copied())
+            .collect();
+        indices.sort_unstable();
+
+        indices
+            .into_iter()
+            .map(|index| &self.formatted_rows[index])
+            .filter(|row| !row.selected_columns.is_empty())
+            .collect()
+    }
+
+    /// Returns the selected displayed rows as ranges of consecutive display indices.
+    ///
+    /// Cheaper than [`selected_rows`](#method.selected_rows) for large selections as only one
+    /// range is returned for each block of rows, and useful for acting on blocks of rows such as
+    /// bulk deletes or exports.
+    /// Selected rows that are not displayed are not included.
+    ///
+    /// # Returns:
+    /// - `Vec<RangeInclusive<usize>>`: The ranges of selected display indices in ascending order.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// for range in table.selected_ranges() {
+    ///     println!("Rows {} to {} are selected", range.start(), range.end());
+    /// }
+    /// ```
+    pub fn selected_ranges(&self) -> Vec<RangeInclusive<usize>> {
+        let mut indices: Vec<usize> = self
+            .active_rows
+            .iter()
+            .filter_map(|id| self.indexed_ids.get(id).copied())
+            .collect();
+        indices.sort_unstable();
+
+        let mut ranges: Vec<RangeInclusive<usize>> = Vec::new();
+        for index in indices {
+            match ranges.last_mut() {
+                Some(range) if *range.end() + 1 == index => *range = *range.start()..=index,
+                _ => ranges.push(index..=index),
+            }
+        }
+        ranges
+    }
+
+    /// Returns how much of the currently displayed rows are selected.
+    ///
+    /// A row counts as selected if it has at least one selected column. Rows that are not
+    /// displayed are not considered.
+    ///
+    /// # Returns:
+    /// - `SelectionState::None` if no displayed row is selected or there are no displayed rows.
+    /// - `SelectionState::Some` if only a part of the displayed rows are selected.
+    /// - `SelectionState::All` if every displayed row is selected.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let all_selected = table.selection_state() == SelectionState::All;
+    /// ```
+    pub fn selection_state(&self) -> SelectionState {
+        let selected_count = self
+            .active_rows
+            .iter()
+            .filter(|id| self.indexed_ids.contains_key(id))
+            .count();
+
+        if selected_count == 0 {
+            SelectionState::None
+        } else if selected_count >= self.formatted_rows.len() {
+            SelectionState::All
+        } else {
+            SelectionState::Some
+        }
+    }
+
+    /// Copies selected cells to the system clipboard in a tabular format.
+    ///
+    /// This method copies only the selected cells from each row to the clipboard. The text is
+    /// built by the formatter set with [`set_copy_formatter`](#method.set_copy_formatter), which
+    /// by default ensures that the column widths align for better readability when pasted into a
+    /// text editor or spreadsheet.
+    ///
+    /// # Parameters:
+    /// - `ui`: The UI context used for clipboard interaction.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.copy_selected_cells(&mut ui);
+    /// ```
+    pub fn copy_selected_cells(&mut self, ui: &mut Ui) {
+        self.copy_selected_to_clipboard(ui.ctx());
+    }
+
+    /// Copies selected cells to the system clipboard using the egui context.
+    ///
+    /// Works the same as [`copy_selected_cells`](#method.copy_selected_cells) but does not need
+    /// a `Ui`, so it can be called from places such as a toolbar button outside the table.
+    ///
+    /// # Parameters:
+    /// - `ctx`: The egui context used for clipboard interaction.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// if ui.button("Copy").clicked() {
+    ///     table.copy_selected_to_clipboard(ui.ctx());
+    /// }
+    /// ```
+    pub fn copy_selected_to_clipboard(&mut self, ctx: &Context) {
+        let mut selected_rows = Vec::new();
+        if self.select_full_row {
+            self.active_columns.extend(self.all_columns.clone());
+        }
+
+        // Iter through all the rows and find the rows that have at least one column as selected
+        // active rows cannot be used here because hashset does not maintain an order.
+        // So itering will give the rows in a different order than what is shown in the ui
+        for row in &self.formatted_rows {
+            if row.selected_columns.is_empty() {
+                continue;
+            }
+            selected_rows.push(row);
+            // We already got all the active rows if this matches
+            if selected_rows.len() == self.active_rows.len() {
+                break;
+            }
+        }
+
+        // Columns that will get a value or an empty space in the copied text
+        let mut copy_columns = self.active_columns.clone();
+
+        // Expand the selection to the bounding rectangle so that unselected cells within it are
+        // copied as empty cells, keeping the rows aligned
+        if self.copy_rectangular_fill {
+            if let (Some(first_row), Some(last_row)) = (selected_rows.first(), selected_rows.last())
+            {
+                let start_index = self.indexed_ids[&first_row.id];
+                let end_index = self.indexed_ids[&last_row.id];
+                selected_rows = self.formatted_rows[start_index..=end_index]
+                    .iter()
+                    .collect();
+            }
+
+            let column_nums: Vec<usize> = self
+                .active_columns
+                .iter()
+                // Hidden columns can stay selected but are not part of the copy
+                .filter_map(|column| self.column_number.get(column).copied())
+                .collect();
+
+            if let (Some(min_num), Some(max_num)) =
+                (column_nums.iter().min(), column_nums.iter().max())
+            {
+                copy_columns.extend(self.all_columns[*min_num..=*max_num].iter().cloned());
+            }
+        }
+
+        // Keep the columns in the same order as they are shown in the ui
+        let mut ordered_columns = Vec::new();
+        if let (Some(mut ongoing_column), Some(last_column)) =
+            (self.first_column(), self.last_column())
+        {
+            loop {
+                if copy_columns.contains(&ongoing_column) {
+                    ordered_columns.push(ongoing_column.clone());
+                }
+                if last_column == ongoing_column {
+                    break;
+                }
+                ongoing_column = self.next_column(&ongoing_column);
+            }
+        }
+
+        let deduped_rows;
+        if self.copy_dedup {
+            deduped_rows = dedup_rows::<Row, F, Conf>(&selected_rows, &ordered_columns);
+            selected_rows = deduped_rows.iter().collect();
+        }
+
+        let rows_to_format: Vec<(&SelectableRow<Row, F>, &[F])> = selected_rows
+            .into_iter()
+            .map(|row| (row, ordered_columns.as_slice()))
+            .collect();
+
+        let to_copy = self.copy_formatter.format(&rows_to_format);
+
+        ctx.output_mut(|i| i.copied_text = to_copy);
+    }
+
+    /// Sets the formatter used to build the copied text from the selected cells.
+    ///
+    /// By default, the cells are copied with [`AlignedFormatter`](crate::AlignedFormatter).
+    /// Built-in alternatives are [`TsvFormatter`](crate::TsvFormatter),
+    /// [`CsvFormatter`](crate::CsvFormatter) and [`MarkdownFormatter`](crate::MarkdownFormatter),
+    /// or implement [`CopyFormatter`] for a custom format.
+    ///
+    /// # Parameters:
+    /// - `formatter`: The formatter to use for copying.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_copy_formatter(Box::new(CsvFormatter));
+    /// ```
+    pub fn set_copy_formatter(&mut self, formatter: Box<dyn CopyFormatter<Row, F, Conf> + Send>) {
+        self.copy_formatter = formatter;
+    }
+
+    /// Enables copying the bounding rectangle of the selection.
+    ///
+    /// After calling this method, copying will include every cell within the rectangle formed by
+    /// the selected cells. Unselected cells inside the rectangle are copied as empty values,
+    /// keeping the output aligned when pasted into a spreadsheet.
+    ///
+    /// # Returns:
+    /// A new instance of the table with rectangular copy enabled.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .copy_rectangular_fill();
+    /// ```
+    #[must_use]
+    pub const fn copy_rectangular_fill(mut self) -> Self {
+        self.copy_rectangular_fill = true;
+        self
+    }
+
+    /// Sets whether copying should include every cell within the bounding rectangle of the
+    /// selection.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to enable rectangular copy, `false` to copy only the selected cells.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_copy_rectangular_fill(true);
+    /// ```
+    pub const fn set_copy_rectangular_fill(&mut self, status: bool) {
+        self.copy_rectangular_fill = status;
+    }
+
+    /// Enables leaving repeated values empty when copying.
+    ///
+    /// After calling this method, a copied cell with the same text as the cell above it in the
+    /// same column is copied as an empty cell, like merged cells in a spreadsheet. The rows are
+    /// compared in display order. Useful for columns such as categories where the repeated
+    /// labels are noise when pasted.
+    ///
+    /// # Returns:
+    /// A new instance of the table with deduplicated copy enabled.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .copy_dedup();
+    /// ```
+    #[must_use]
+    pub const fn copy_dedup(mut self) -> Self {
+        self.copy_dedup = true;
+        self
+    }
+
+    /// Sets whether repeated values in a column should be left empty when copying.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to leave repeated values empty, `false` to copy every value.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_copy_dedup(true);
+    /// ```
+    pub const fn set_copy_dedup(&mut self, status: bool) {
+        self.copy_dedup = status;
+    }
+
+    /// Enables the selection of full rows in the table.
+    ///
+    /// After calling this method, selecting any column in a row will result in the entire row being selected.
+    ///
+    /// # Returns:
+    /// A new instance of the table with full row selection enabled.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .select_full_row();
+    /// ```
+    #[must_use]
+    pub const fn select_full_row(mut self) -> Self {
+        self.select_full_row = true;
+        self
+    }
+
+    /// Sets whether the table should select full rows when a column is selected.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to enable full row selection, `false` to disable it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_select_full_row(true); // Enable full row selection.
+    /// ```
+    pub const fn set_select_full_row(&mut self, status: bool) {
+        self.select_full_row = status;
+    }
+
+    /// Constrains drag selection to the row where the drag started.
+    ///
+    /// After calling this method, dragging will only extend the selection across the columns of
+    /// the starting row. Moving the pointer to other rows will not select them.
+    ///
+    /// # Returns:
+    /// A new instance of the table with single row drag selection enabled.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .single_row_drag();
+    /// ```
+    #[must_use]
+    pub const fn single_row_drag(mut self) -> Self {
+        self.single_row_drag = true;
+        self
+    }
+
+    /// Sets whether drag selection should be constrained to the row where the drag started.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to constrain drag selection to a single row, `false` to disable it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_single_row_drag(true);
+    /// ```
+    pub const fn set_single_row_drag(&mut self, status: bool) {
+        self.single_row_drag = status;
+    }
+
+    /// Makes dragging with the secondary mouse button add the dragged cells to the existing
+    /// selection, the same way a CTRL drag does.
+    ///
+    /// A right click without moving the pointer is not affected and can still open a context
+    /// menu. Does nothing if the selection mode is [`SelectionMode::Single`].
+    ///
+    /// # Returns:
+    /// An updated instance of the table with additive right drag enabled.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .right_drag_adds_selection();
+    /// ```
+    #[must_use]
+    pub const fn right_drag_adds_selection(mut self) -> Self {
+        self.right_drag_adds_selection = true;
+        self
+    }
+
+    /// Sets whether dragging with the secondary mouse button should add to the existing
+    /// selection.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to make right drags additive, `false` to disable it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_right_drag_adds_selection(true);
+    /// ```
+    pub const fn set_right_drag_adds_selection(&mut self, status: bool) {
+        self.right_drag_adds_selection = status;
+    }
+
+    /// Sets whether pressing on a cell that is part of a multi-selection keeps the selection.
+    ///
+    /// By default, pressing on any cell clears the selection and starts a new one. When enabled,
+    /// dragging from an already selected cell keeps the current selection and does not start a
+    /// drag selection, allowing the selected group to be dragged elsewhere. Releasing the
+    /// pointer without dragging still selects only the clicked cell.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to keep the multi-selection on press, `false` to disable it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_click_preserves_multiselect(true);
+    /// ```
+    pub const fn set_click_preserves_multiselect(&mut self, status: bool) {
+        self.click_preserves_multiselect = status;
+    }
+
+    /// Sets how long the pointer must be held down before a drag starts selecting cells.
+    ///
+    /// Useful on touchscreens where a tap often registers a tiny movement that would otherwise
+    /// start a drag selection. A drag released before the delay is treated as a click on the cell
+    /// where it started. Default: no delay.
+    ///
+    /// # Parameters:
+    /// - `delay`: The minimum time the pointer must be held down.
+    ///
+    /// # Returns:
+    /// An updated instance of the table with the drag delay set.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .drag_delay(Duration::from_millis(150));
+    /// ```
+    #[must_use]
+    pub const fn drag_delay(mut self, delay: Duration) -> Self {
+        self.drag_delay = delay;
+        self
+    }
+
+    /// Sets how long the pointer must be held down before a drag starts selecting cells.
+    ///
+    /// # Parameters:
+    /// - `delay`: The minimum time the pointer must be held down. `Duration::ZERO` disables it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_drag_delay(Duration::from_millis(150));
+    /// ```
+    pub const fn set_drag_delay(&mut self, delay: Duration) {
+        self.drag_delay = delay;
+    }
+
+    /// Sets whether a single or multiple cells can be selected at the same time.
+    ///
+    /// In [`SelectionMode::Single`], any new selection replaces the previous one even with Ctrl
+    /// held, dragging moves the selection to the cell under the pointer, and select all does
+    /// nothing.
+    ///
+    /// # Parameters:
+    /// - `mode`: The `SelectionMode` to use.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_selection_mode(SelectionMode::Single);
+    /// ```
+    pub fn set_selection_mode(&mut self, mode: SelectionMode) {
+        if mode == SelectionMode::Single && self.active_rows.len() > 1 {
+            self.unselect_all();
+        }
+        self.selection_mode = mode;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{Response, Ui};
+    use std::cmp::Ordering;
+
+    use crate::{ColumnOperations, ColumnOrdering, SelectableRow, SelectableTable, SortOrder};
+
+    #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+    enum Column {
+        #[default]
+        A,
+        B,
+        C,
+    }
+
+    impl ColumnOperations<i64, Self, ()> for Column {
+        fn create_header(
+            &self,
+            _ui: &mut Ui,
+            _sort_order: Option<SortOrder>,
+            _table: &mut SelectableTable<i64, Self, ()>,
+        ) -> Option<Response> {
+            None
+        }
+
+        fn create_table_row(
+            &self,
+            ui: &mut Ui,
+            row: &SelectableRow<i64, Self>,
+            _column_selected: bool,
+            _table: &mut SelectableTable<i64, Self, ()>,
+        ) -> Response {
+            ui.label(row.row_data.to_string())
+        }
+
+        fn column_text(&self, row: &i64) -> String {
+            row.to_string()
+        }
+    }
+
+    impl ColumnOrdering<i64> for Column {
+        fn order_by(&self, row_1: &i64, row_2: &i64) -> Ordering {
+            row_1.cmp(row_2)
+        }
+    }
+
+    /// A table with 4 rows displayed in ID order
+    fn table() -> SelectableTable<i64, Column, ()> {
+        let mut table = SelectableTable::new(vec![Column::A, Column::B, Column::C]);
+        for value in 0..4 {
+            table.add_modify_row(|_| Some(value));
+        }
+        table.recreate_rows();
+        table
+    }
+
+    /// Drag from the start cell through each of the given cells and release, the same way the
+    /// table body does with the add or subtract modifier held
+    fn drag(
+        table: &mut SelectableTable<i64, Column, ()>,
+        start: (i64, Column),
+        path: &[(i64, Column)],
+        subtract: bool,
+    ) {
+        if subtract {
+            table.subtract_drag_base = Some(table.get_selection());
+        }
+        table.drag_started_on = Some(start);
+        for (id, column) in path {
+            if subtract {
+                table.subtract_dragged_row_cell(*id, column);
+            } else {
+                table.select_dragged_row_cell(*id, column, false);
+            }
+        }
+        table.last_active_row = None;
+        table.last_active_column = None;
+        table.drag_started_on = None;
+        table.subtract_drag_base = None;
+        table.beyond_drag_point = false;
+    }
+
+    fn selected_columns(table: &SelectableTable<i64, Column, ()>, id: i64) -> Vec<Column> {
+        let mut columns: Vec<Column> = table.formatted_rows[table.indexed_ids[&id]]
+            .selected_columns
+            .iter()
+            .copied()
+            .collect();
+        columns.sort_unstable();
+        columns
+    }
+
+    fn active_rows(table: &SelectableTable<i64, Column, ()>) -> Vec<i64> {
+        let mut rows: Vec<i64> = table.active_rows.iter().copied().collect();
+        rows.sort_unstable();
+        rows
+    }
+
+    fn active_columns(table: &SelectableTable<i64, Column, ()>) -> Vec<Column> {
+        let mut columns: Vec<Column> = table.active_columns.iter().copied().collect();
+        columns.sort_unstable();
+        columns
+    }
+
+    #[test]
+    fn subtract_drag_removes_rectangle_from_added_selection() {
+        let mut table = table();
+        drag(&mut table, (0, Column::A), &[(2, Column::C)], false);
+        assert_eq!(active_rows(&table), vec![0, 1, 2]);
+
+        drag(&mut table, (1, Column::B), &[(2, Column::C)], true);
+
+        assert_eq!(active_rows(&table), vec![0, 1, 2]);
+        assert_eq!(
+            active_columns(&table),
+            vec![Column::A, Column::B, Column::C]
+        );
+        assert_eq!(
+            selected_columns(&table, 0),
+            vec![Column::A, Column::B, Column::C]
+        );
+        assert_eq!(selected_columns(&table, 1), vec![Column::A]);
+        assert_eq!(selected_columns(&table, 2), vec![Column::A]);
+        assert!(selected_columns(&table, 3).is_empty());
+    }
+
+    #[test]
+    fn subtract_drag_unselects_emptied_rows_and_columns() {
+        let mut table = table();
+        drag(&mut table, (0, Column::A), &[(1, Column::B)], false);
+
+        drag(&mut table, (1, Column::A), &[(1, Column::B)], true);
+        assert_eq!(active_rows(&table), vec![0]);
+        assert_eq!(active_columns(&table), vec![Column::A, Column::B]);
+
+        drag(&mut table, (0, Column::B), &[(0, Column::C)], true);
+        assert_eq!(active_rows(&table), vec![0]);
+        assert_eq!(active_columns(&table), vec![Column::A]);
+        assert_eq!(selected_columns(&table, 0), vec![Column::A]);
+        assert!(selected_columns(&table, 1).is_empty());
+    }
+
+    #[test]
+    fn subtract_drag_restores_cells_that_leave_the_rectangle() {
+        let mut table = table();
+        drag(&mut table, (0, Column::A), &[(3, Column::C)], false);
+
+        // Shrinking the rectangle gives back the cells it no longer covers
+        drag(
+            &mut table,
+            (0, Column::A),
+            &[(3, Column::C), (1, Column::B), (0, Column::A)],
+            true,
+        );
+
+        assert_eq!(active_rows(&table), vec![0, 1, 2, 3]);
+        assert_eq!(
+            active_columns(&table),
+            vec![Column::A, Column::B, Column::C]
+        );
+        assert_eq!(selected_columns(&table, 0), vec![Column::B, Column::C]);
+        for id in 1..4 {
+            assert_eq!(
+                selected_columns(&table, id),
+                vec![Column::A, Column::B, Column::C]
+            );
+        }
+    }
+
+    #[test]
+    fn add_after_subtract_selects_the_cells_again() {
+        let mut table = table();
+        drag(&mut table, (0, Column::A), &[(2, Column::B)], false);
+        drag(&mut table, (0, Column::A), &[(2, Column::B)], true);
+        assert!(active_rows(&table).is_empty());
+        assert!(active_columns(&table).is_empty());
+
+        drag(&mut table, (1, Column::B), &[(2, Column::C)], false);
+
+        assert_eq!(active_rows(&table), vec![1, 2]);
+        assert_eq!(active_columns(&table), vec![Column::B, Column::C]);
+        assert!(selected_columns(&table, 0).is_empty());
+        assert_eq!(selected_columns(&table, 1), vec![Column::B, Column::C]);
+        assert_eq!(selected_columns(&table, 2), vec![Column::B, Column::C]);
     }
 }
@@ -0,0 +1,126 @@
+use egui::ahash::HashMap;
+use std::hash::Hash;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableRow, SelectableTable};
+
+/// Functions related to hiding individual rows from the display
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Hides or shows a single row without removing it from the table.
+    ///
+    /// Unlike the column filters, hiding is decided per row ID, for example for a per-row
+    /// collapse toggle. Changes are not immediately reflected in the UI. Call
+    /// [`refresh_visibility`](#method.refresh_visibility) to apply them without re-sorting, or
+    /// [`recreate_rows`](#method.recreate_rows).
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row to hide or show.
+    /// - `hidden`: `true` to hide the row, `false` to show it again.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_row_hidden(row_id, true);
+    /// table.refresh_visibility();
+    /// ```
+    pub fn set_row_hidden(&mut self, id: i64, hidden: bool) {
+        if hidden {
+            self.hidden_rows.insert(id);
+        } else {
+            self.hidden_rows.remove(&id);
+        }
+    }
+
+    /// Returns whether the row was hidden with [`set_row_hidden`](#method.set_row_hidden).
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row to check.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let collapsed = table.is_row_hidden(row_id);
+    /// ```
+    pub fn is_row_hidden(&self, id: i64) -> bool {
+        self.hidden_rows.contains(&id)
+    }
+
+    /// Rebuilds the displayed rows from the order of the last sort, leaving out the hidden rows.
+    ///
+    /// The sort comparator is not run again, so this is much cheaper than
+    /// [`recreate_rows`](#method.recreate_rows) when only the hidden rows changed. Rows added
+    /// since the last sort are not displayed until the rows are recreated. The selection of
+    /// hidden rows is kept and restored once they are shown again.
+    ///
+    /// Rows that stay displayed are kept as is, including changes made with
+    /// [`modify_shown_row`](#method.modify_shown_row). Rows that are shown again are created
+    /// from the stored rows, so changes to a row made before it was hidden are lost.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_row_hidden(row_id, false);
+    /// table.refresh_visibility();
+    /// ```
+    pub fn refresh_visibility(&mut self) {
+        // Save the selection of the displayed rows, including the ones about to be hidden
+        for id in self.active_rows.drain() {
+            if let Some(index) = self.indexed_ids.get(&id) {
+                let selected_columns =
+                    std::mem::take(&mut self.formatted_rows[*index].selected_columns);
+                self.hidden_selection.insert(id, selected_columns);
+            }
+        }
+        self.active_columns.clear();
+
+        // Reuse the displayed rows so they keep any modification made to them
+        let mut shown_rows: HashMap<i64, SelectableRow<Row, F>> =
+            std::mem::take(&mut self.formatted_rows)
+                .into_iter()
+                .map(|row| (row.id, row))
+                .collect();
+
+        let hidden_rows = &self.hidden_rows;
+        let rows = &self.rows;
+        let view_transform = self.view_transform.as_deref();
+        self.formatted_rows = self
+            .sorted_ids
+            .iter()
+            .filter(|id| !hidden_rows.contains(id))
+            .filter_map(|id| {
+                let row = rows.get(id)?;
+                Some(
+                    shown_rows
+                        .remove(id)
+                        .unwrap_or_else(|| Self::view_row(view_transform, row)),
+                )
+            })
+            .collect();
+        self.indexed_ids = self
+            .formatted_rows
+            .iter()
+            .enumerate()
+            .map(|(index, row)| (row.id, index))
+            .collect::<HashMap<i64, usize>>();
+
+        let saved_selection = std::mem::take(&mut self.hidden_selection);
+        for (id, selected_columns) in saved_selection {
+            if let Some(index) = self.indexed_ids.get(&id) {
+                self.active_columns.extend(selected_columns.iter().cloned());
+                self.formatted_rows[*index].selected_columns = selected_columns;
+                self.active_rows.insert(id);
+            } else if self.hidden_rows.contains(&id) && self.rows.contains_key(&id) {
+                self.hidden_selection.insert(id, selected_columns);
+            }
+        }
+    }
+}
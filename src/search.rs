@@ -0,0 +1,177 @@
+use egui::ahash::HashMap;
+use rayon::prelude::*;
+use std::hash::Hash;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableRow, SelectableTable};
+
+/// The percentage of the text covered by the query, or `None` if the text does not contain it.
+/// Both are expected to be lowercase
+fn match_score(text: &str, query: &str) -> Option<u32> {
+    if !text.contains(query) {
+        return None;
+    }
+    let text_length = text.chars().count().max(1);
+    let query_length = query.chars().count();
+
+    #[allow(clippy::cast_possible_truncation)]
+    Some((query_length * 100 / text_length) as u32)
+}
+
+/// Functions related to searching the rows
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Marks the rows where the search text of any of the columns contains the query, ignoring
+    /// case, without changing the displayed rows.
+    ///
+    /// Unlike filtering, every row stays displayed so the matches can be seen in context. Check
+    /// [`is_match`](#method.is_match) inside `create_table_row` to highlight the matched rows.
+    /// An empty query removes all marks.
+    ///
+    /// # Parameters:
+    /// - `columns`: The columns to search in.
+    /// - `query`: The text to search for.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.highlight_and_show(&[ColumnName::Name, ColumnName::Email], &query);
+    /// ```
+    pub fn highlight_and_show(&mut self, columns: &[F], query: &str) {
+        if query.is_empty() {
+            self.matched_rows.clear();
+            return;
+        }
+
+        let query = query.to_lowercase();
+        let best_score = |(id, row): (&i64, &SelectableRow<Row, F>)| {
+            columns
+                .iter()
+                .filter_map(|column| {
+                    match_score(&column.search_text(&row.row_data).to_lowercase(), &query)
+                })
+                .max()
+                .map(|score| (*id, score))
+        };
+        self.matched_rows = if self.single_threaded {
+            self.rows
+                .iter()
+                .filter_map(best_score)
+                .collect::<HashMap<i64, u32>>()
+        } else {
+            self.rows
+                .par_iter()
+                .filter_map(best_score)
+                .collect::<HashMap<i64, u32>>()
+        };
+    }
+
+    /// Narrows the displayed rows down to the ones where the search text of any of the columns
+    /// contains the query, ignoring case.
+    ///
+    /// Only the currently displayed rows are searched, so calling this again with another query
+    /// refines the previous results. The rows are not removed from the table and the display
+    /// order is kept. Selected rows that no longer match are unselected. Hidden rows are not
+    /// searched and stay hidden, so [`refresh_visibility`](#method.refresh_visibility) keeps the
+    /// results. Call [`recreate_rows`](#method.recreate_rows) to go back to displaying every row.
+    ///
+    /// # Parameters:
+    /// - `columns`: The columns to search in.
+    /// - `query`: The text to search for. An empty query leaves the displayed rows as is.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.refine_search(&[ColumnName::Name], "john");
+    /// // Drill down further within the previous results
+    /// table.refine_search(&[ColumnName::Email], "example.com");
+    /// ```
+    pub fn refine_search(&mut self, columns: &[F], query: &str) {
+        if query.is_empty() {
+            return;
+        }
+
+        let query = query.to_lowercase();
+        self.formatted_rows.retain(|row| {
+            columns.iter().any(|column| {
+                column
+                    .search_text(&row.row_data)
+                    .to_lowercase()
+                    .contains(&query)
+            })
+        });
+        self.indexed_ids = self
+            .formatted_rows
+            .iter()
+            .enumerate()
+            .map(|(index, row)| (row.id, index))
+            .collect::<HashMap<i64, usize>>();
+
+        // Narrow the sort order too so refresh_visibility does not bring back the rows that no
+        // longer match. Hidden rows were not searched so they are kept
+        let indexed_ids = &self.indexed_ids;
+        let hidden_rows = &self.hidden_rows;
+        self.sorted_ids
+            .retain(|id| indexed_ids.contains_key(id) || hidden_rows.contains(id));
+        self.hidden_selection
+            .retain(|id, _| indexed_ids.contains_key(id) || hidden_rows.contains(id));
+
+        self.active_rows.retain(|id| indexed_ids.contains_key(id));
+        self.active_columns = self
+            .active_rows
+            .iter()
+            .flat_map(|id| self.formatted_rows[indexed_ids[id]].selected_columns.iter())
+            .cloned()
+            .collect();
+    }
+
+    /// Returns whether the row matched the last
+    /// [`highlight_and_show`](#method.highlight_and_show) query.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// // Inside create_table_row
+    /// if table.is_match(row.id) {
+    ///     ui.painter().rect_filled(ui.max_rect(), 0.0, Color32::DARK_GREEN);
+    /// }
+    /// ```
+    pub fn is_match(&self, id: i64) -> bool {
+        self.matched_rows.contains_key(&id)
+    }
+
+    /// Returns how closely the row matched the last
+    /// [`highlight_and_show`](#method.highlight_and_show) query.
+    ///
+    /// The score is the percentage of the searched text covered by the query in the best
+    /// matching column, so an exact match scores `100`.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row.
+    ///
+    /// # Returns:
+    /// - `Option<u32>`: The score of the row from `0` to `100`, or `None` if the row did not
+    ///   match.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// // Inside create_table_row
+    /// if let Some(score) = table.match_score(row.id) {
+    ///     ui.add(ProgressBar::new(score as f32 / 100.0));
+    /// }
+    /// ```
+    pub fn match_score(&self, id: i64) -> Option<u32> {
+        self.matched_rows.get(&id).copied()
+    }
+}
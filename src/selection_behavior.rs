@@ -0,0 +1,96 @@
+use egui::Modifiers;
+use std::hash::Hash;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableTable};
+
+/// What a click or a drag does to the existing selection
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SelectionAction {
+    /// Clears the existing selection before selecting
+    Replace,
+    /// Selects on top of the existing selection
+    Add,
+    /// Removes from the existing selection
+    Subtract,
+}
+
+/// Which modifiers change what a click or a drag does to the existing selection. Without any of
+/// them held, the selection is replaced.
+#[derive(Clone, Copy)]
+pub struct SelectionBehavior {
+    /// Adds to the existing selection. Default: `Ctrl`
+    pub add: Option<Modifiers>,
+    /// Removes from the existing selection. Checked before `add`. Default: `Alt`
+    pub subtract: Option<Modifiers>,
+}
+
+impl Default for SelectionBehavior {
+    fn default() -> Self {
+        Self {
+            add: Some(Modifiers::CTRL),
+            subtract: Some(Modifiers::ALT),
+        }
+    }
+}
+
+impl SelectionBehavior {
+    /// The action of a click or a drag made with the held modifiers
+    pub(crate) fn action(&self, modifiers: Modifiers) -> SelectionAction {
+        if self
+            .subtract
+            .is_some_and(|subtract| modifiers.contains(subtract))
+        {
+            SelectionAction::Subtract
+        } else if self.add.is_some_and(|add| modifiers.contains(add)) {
+            SelectionAction::Add
+        } else {
+            SelectionAction::Replace
+        }
+    }
+}
+
+/// Functions related to how clicks and drags change the selection
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Sets which modifiers add to or remove from the existing selection on click or drag.
+    ///
+    /// Only applies when multiple selections are allowed. A click with the subtract modifiers
+    /// held unselects the clicked cell, or the row if full row selection is enabled. A drag
+    /// with them held unselects every cell in the dragged rectangle.
+    ///
+    /// # Parameters:
+    /// - `behavior`: The modifiers for each action. Set one to `None` to disable it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_selection_behavior(SelectionBehavior {
+    ///     add: Some(Modifiers::COMMAND),
+    ///     subtract: Some(Modifiers::ALT),
+    /// });
+    /// ```
+    pub const fn set_selection_behavior(&mut self, behavior: SelectionBehavior) {
+        self.selection_behavior = behavior;
+    }
+
+    /// Returns which modifiers add to or remove from the existing selection.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let behavior = table.selection_behavior();
+    /// ```
+    pub const fn selection_behavior(&self) -> SelectionBehavior {
+        self.selection_behavior
+    }
+}
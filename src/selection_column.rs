@@ -0,0 +1,144 @@
+use egui::{Checkbox, Layout, Ui};
+use std::hash::Hash;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableTable, SelectionMode};
+
+/// Functions related to the checkbox column for selecting rows
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Shows a checkbox in the header that selects or unselects every displayed row
+    pub(crate) fn selection_header_checkbox(&mut self, ui: &mut Ui) {
+        let mut all_selected =
+            !self.formatted_rows.is_empty() && self.active_rows.len() == self.formatted_rows.len();
+        let partially_selected = !all_selected && self.has_selection();
+
+        let checkbox = Checkbox::without_text(&mut all_selected).indeterminate(partially_selected);
+        let resp = ui
+            .with_layout(
+                Layout::centered_and_justified(ui.layout().main_dir()),
+                |ui| ui.add(checkbox),
+            )
+            .inner;
+
+        if resp.clicked() {
+            if all_selected {
+                self.select_all();
+            } else {
+                self.unselect_all();
+            }
+        }
+    }
+
+    /// Shows a checkbox reflecting whether the row is fully, partially or not selected
+    pub(crate) fn selection_row_checkbox(&mut self, ui: &mut Ui, index: usize) {
+        let row_data = &self.formatted_rows[index];
+        let id = row_data.id;
+        let mut fully_selected = self
+            .all_columns
+            .iter()
+            .all(|column| row_data.selected_columns.contains(column));
+        let partially_selected = !fully_selected && !row_data.selected_columns.is_empty();
+
+        let checkbox =
+            Checkbox::without_text(&mut fully_selected).indeterminate(partially_selected);
+        let resp = ui
+            .with_layout(
+                Layout::centered_and_justified(ui.layout().main_dir()),
+                |ui| ui.add(checkbox),
+            )
+            .inner;
+
+        if resp.clicked() {
+            self.toggle_row_selection(id);
+        }
+    }
+
+    /// Selects every column of the row if no cell of it is selected, otherwise unselects the
+    /// row.
+    ///
+    /// In [`SelectionMode::Single`], the rest of the selection is cleared and only the first
+    /// cell of the row is selected, or the whole row if full row selection is enabled.
+    ///
+    /// # Parameters:
+    /// - `id`: The ID of the row to toggle.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.toggle_row_selection(row_id);
+    /// ```
+    pub fn toggle_row_selection(&mut self, id: i64) {
+        let Some(index) = self.indexed_ids.get(&id).copied() else {
+            return;
+        };
+
+        if !self.formatted_rows[index].selected_columns.is_empty() {
+            self.unselect_row(id);
+            return;
+        }
+
+        if self.selection_mode == SelectionMode::Single {
+            self.unselect_all();
+            if let Some(column) = self.first_column() {
+                self.select_single_row_cell(id, &column);
+            }
+            return;
+        }
+
+        if self.is_selection_full() {
+            self.selection_truncated = true;
+            return;
+        }
+
+        self.formatted_rows[index]
+            .selected_columns
+            .extend(self.all_columns.clone());
+        self.active_columns.extend(self.all_columns.clone());
+        self.active_rows.insert(id);
+    }
+
+    /// Adds a leading column with a checkbox for each row that shows and changes whether the
+    /// row is selected.
+    ///
+    /// The checkbox is checked when every column of the row is selected and partially checked
+    /// when only some are. The checkbox in the header selects or unselects every displayed row.
+    /// The column is added internally, before the serial column if enabled.
+    ///
+    /// # Returns:
+    /// - `Self`: The modified table with the selection column enabled.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .selection_column();
+    /// ```
+    #[must_use]
+    pub const fn selection_column(mut self) -> Self {
+        self.add_selection_column = true;
+        self
+    }
+
+    /// Sets whether the leading checkbox column for selecting rows should be shown.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to show the selection column, `false` to hide it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_selection_column(true);
+    /// ```
+    pub const fn set_selection_column(&mut self, status: bool) {
+        self.add_selection_column = status;
+    }
+}
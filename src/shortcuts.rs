@@ -0,0 +1,112 @@
+use egui::{Event, InputState, Key, KeyboardShortcut, Modifiers};
+use std::hash::Hash;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableTable};
+
+/// The copy shortcut of the platform. Integrations report it as [`Event::Copy`] instead of a key
+/// press
+const PLATFORM_COPY: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::C);
+
+/// Keyboard shortcuts handled by the table. `None` disables the shortcut.
+#[derive(Clone, Copy)]
+pub struct Shortcuts {
+    /// Selects every displayed cell. Default: `Ctrl+A`
+    pub select_all: Option<KeyboardShortcut>,
+    /// Copies the selected cells to the clipboard. Default: the platform copy shortcut
+    pub copy: Option<KeyboardShortcut>,
+    /// Reverts the latest edit made with `edit_row`. Default: `None`
+    pub undo_edit: Option<KeyboardShortcut>,
+    /// Applies the latest reverted edit again. Default: `None`
+    pub redo_edit: Option<KeyboardShortcut>,
+}
+
+impl Default for Shortcuts {
+    fn default() -> Self {
+        Self {
+            select_all: Some(KeyboardShortcut::new(Modifiers::CTRL, Key::A)),
+            copy: Some(PLATFORM_COPY),
+            undo_edit: None,
+            redo_edit: None,
+        }
+    }
+}
+
+impl Shortcuts {
+    /// Whether the select all shortcut was pressed in this frame
+    pub(crate) fn select_all_pressed(&self, input: &InputState) -> bool {
+        self.select_all
+            .is_some_and(|shortcut| Self::shortcut_pressed(input, shortcut))
+    }
+
+    /// Whether the copy shortcut was pressed in this frame
+    pub(crate) fn copy_pressed(&self, input: &InputState) -> bool {
+        self.copy.is_some_and(|shortcut| {
+            if shortcut == PLATFORM_COPY {
+                input.events.contains(&Event::Copy)
+            } else {
+                Self::shortcut_pressed(input, shortcut)
+            }
+        })
+    }
+
+    /// Whether the undo edit shortcut was pressed in this frame
+    pub(crate) fn undo_edit_pressed(&self, input: &InputState) -> bool {
+        self.undo_edit
+            .is_some_and(|shortcut| Self::shortcut_pressed(input, shortcut))
+    }
+
+    /// Whether the redo edit shortcut was pressed in this frame
+    pub(crate) fn redo_edit_pressed(&self, input: &InputState) -> bool {
+        self.redo_edit
+            .is_some_and(|shortcut| Self::shortcut_pressed(input, shortcut))
+    }
+
+    fn shortcut_pressed(input: &InputState, shortcut: KeyboardShortcut) -> bool {
+        input.modifiers.matches_logically(shortcut.modifiers)
+            && input.key_pressed(shortcut.logical_key)
+    }
+}
+
+/// Functions related to the keyboard shortcuts of the table
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Sets the keyboard shortcuts handled by the table.
+    ///
+    /// # Parameters:
+    /// - `shortcuts`: The shortcuts to use. Set a shortcut to `None` to disable it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_shortcuts(Shortcuts {
+    ///     select_all: None,
+    ///     copy: Some(KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::C)),
+    ///     undo_edit: Some(KeyboardShortcut::new(Modifiers::COMMAND, Key::Z)),
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub const fn set_shortcuts(&mut self, shortcuts: Shortcuts) {
+        self.shortcuts = shortcuts;
+    }
+
+    /// Returns the keyboard shortcuts handled by the table.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let shortcuts = table.shortcuts();
+    /// ```
+    pub const fn shortcuts(&self) -> Shortcuts {
+        self.shortcuts
+    }
+}
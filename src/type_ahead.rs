@@ -0,0 +1,123 @@
+use egui::{Event, Ui};
+use std::hash::Hash;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableTable};
+
+/// Seconds without typing after which the typed text starts over
+const RESET_TIMEOUT: f64 = 1.0;
+
+#[derive(Default)]
+pub struct TypeAhead {
+    pub enabled: bool,
+    /// The lowercase text typed so far
+    pub typed: String,
+    /// The time the last character was typed
+    pub last_typed_at: f64,
+}
+
+/// Functions related to jumping to a row by typing the start of its text
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Add the typed characters to the type-ahead text and select the first row where the text
+    /// of the sorted column, or the first column if it is not displayed, starts with it
+    pub(crate) fn handle_type_ahead(&mut self, ui: &Ui) {
+        if !self.type_ahead.enabled || ui.ctx().wants_keyboard_input() {
+            return;
+        }
+
+        let (typed, time) = ui.input(|i| {
+            let typed: String = i
+                .events
+                .iter()
+                .filter_map(|event| match event {
+                    Event::Text(text) => Some(text.as_str()),
+                    _ => None,
+                })
+                .collect();
+            (typed, i.time)
+        });
+        if typed.is_empty() {
+            return;
+        }
+
+        if time - self.type_ahead.last_typed_at > RESET_TIMEOUT {
+            self.type_ahead.typed.clear();
+        }
+        self.type_ahead.last_typed_at = time;
+        self.type_ahead.typed.push_str(&typed.to_lowercase());
+
+        // The sorted column may be hidden or unused with no_sort so the first column is used
+        let column = if self.sorting_enabled && self.all_columns.contains(&self.sorted_by) {
+            self.sorted_by.clone()
+        } else {
+            let Some(column) = self.first_column() else {
+                return;
+            };
+            column
+        };
+
+        let prefix = &self.type_ahead.typed;
+        let Some(index) = self.formatted_rows.iter().position(|row| {
+            column
+                .column_text(&row.row_data)
+                .to_lowercase()
+                .starts_with(prefix.as_str())
+        }) else {
+            return;
+        };
+
+        let id = self.formatted_rows[index].id;
+        self.unselect_all();
+        self.select_single_row_cell(id, &column);
+        self.scroll_to_index = Some(index);
+    }
+
+    /// Enables jumping to a row by typing the start of its text in the sorted column.
+    ///
+    /// Characters typed in quick succession are combined, so typing "ap" jumps to the first row
+    /// starting with "ap". The typed text starts over after a second without typing. Typing is
+    /// ignored while another widget, such as a text edit, has keyboard focus.
+    ///
+    /// If the sorted column is not displayed, for example after hiding it with
+    /// [`set_columns_layout`](#method.set_columns_layout) or when sorting is disabled with
+    /// [`no_sort`](#method.no_sort), the first displayed column is used instead.
+    ///
+    /// # Returns:
+    /// An updated instance of the table with type-ahead enabled.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let table = SelectableTable::new(vec![col1, col2, col3])
+    ///     .type_ahead();
+    /// ```
+    #[must_use]
+    pub const fn type_ahead(mut self) -> Self {
+        self.type_ahead.enabled = true;
+        self
+    }
+
+    /// Sets whether typing should jump to the first row matching the typed text.
+    ///
+    /// # Parameters:
+    /// - `status`: `true` to enable type-ahead, `false` to disable it.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.set_type_ahead(true);
+    /// ```
+    pub const fn set_type_ahead(&mut self, status: bool) {
+        self.type_ahead.enabled = status;
+    }
+}
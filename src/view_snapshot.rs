@@ -0,0 +1,93 @@
+use egui::ahash::{HashMap, HashSet};
+use egui::Vec2;
+use std::hash::Hash;
+
+use crate::{ColumnOperations, ColumnOrdering, SelectableTable, SortOrder};
+
+/// Everything needed to reproduce what the table displays, captured with
+/// [`SelectableTable::snapshot`] and applied with [`SelectableTable::restore`]
+#[derive(Clone)]
+pub struct ViewSnapshot<F> {
+    /// The column the rows are sorted by
+    pub sorted_by: F,
+    /// The order the rows are sorted in
+    pub sort_order: SortOrder,
+    /// The lowercase filter text of each filtered column
+    pub column_filters: HashMap<F, String>,
+    /// The rows marked by the last search with the score of their best match
+    pub matched_rows: HashMap<i64, u32>,
+    /// The displayed columns in display order. Every other column is hidden
+    pub columns: Vec<F>,
+    /// The selected columns of each selected row
+    pub selection: HashMap<i64, HashSet<F>>,
+    /// The horizontal and vertical scroll offset of the table
+    pub scroll_offset: Vec2,
+}
+
+/// Functions related to capturing and restoring the view of the table
+impl<Row, F, Conf> SelectableTable<Row, F, Conf>
+where
+    Row: Clone + Send + Sync,
+    F: Eq
+        + Hash
+        + Clone
+        + Ord
+        + Send
+        + Sync
+        + Default
+        + ColumnOperations<Row, F, Conf>
+        + ColumnOrdering<Row>,
+    Conf: Default,
+{
+    /// Captures the sorting, filters, search matches, column layout, selection and scroll
+    /// offset of the table.
+    ///
+    /// The rows themselves are not part of the snapshot. Useful for comparing two views of the
+    /// same rows or switching back to an earlier view.
+    ///
+    /// # Returns:
+    /// - `ViewSnapshot<F>`: The current view of the table.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// let before = table.snapshot();
+    /// // Change the sort, filters or selection
+    /// table.restore(before);
+    /// ```
+    pub fn snapshot(&self) -> ViewSnapshot<F> {
+        ViewSnapshot {
+            sorted_by: self.sorted_by.clone(),
+            sort_order: self.sort_order,
+            column_filters: self.column_filters.clone(),
+            matched_rows: self.matched_rows.clone(),
+            columns: self.all_columns.clone(),
+            selection: self.get_selection(),
+            scroll_offset: self.scroll_offset,
+        }
+    }
+
+    /// Applies a view captured with [`snapshot`](#method.snapshot).
+    ///
+    /// The column layout, sorting and filters are applied first and the rows are recreated,
+    /// then the selection is restored and the table is scrolled on the next frame. Selected
+    /// rows that no longer exist are ignored.
+    ///
+    /// # Parameters:
+    /// - `snapshot`: The view to apply.
+    ///
+    /// # Example:
+    /// ```rust,ignore
+    /// table.restore(saved_view.clone());
+    /// ```
+    pub fn restore(&mut self, snapshot: ViewSnapshot<F>) {
+        self.set_columns_layout(snapshot.columns);
+        self.sorted_by = snapshot.sorted_by;
+        self.sort_order = snapshot.sort_order;
+        self.column_filters = snapshot.column_filters;
+        self.matched_rows = snapshot.matched_rows;
+
+        self.recreate_rows();
+        self.set_selection(snapshot.selection);
+        self.scroll_to_offset = Some(snapshot.scroll_offset);
+    }
+}